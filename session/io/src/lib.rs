@@ -1,7 +1,11 @@
 #![no_std]
-use gmeta::{InOut, Metadata, Out};
-use gstd::{collections::BTreeMap, prelude::*, ActorId, MessageId};
-use wordle_io::{Event as WordleEvent, WORD_LENGTH};
+use gmeta::{InOut, Metadata};
+use gstd::{collections::BTreeMap, prelude::*, ActorId, MessageId, ReservationId};
+use wordle_io::{
+    Error as WordleError, Event as WordleEvent, GameOutcome, MAX_ATTEMPTS, WORD_LENGTH,
+};
+
+pub use wordle_io::{Alphabet, GameId, LetterFeedback, PuzzleId};
 
 pub struct SessionMetadata;
 impl Metadata for SessionMetadata {
@@ -10,22 +14,137 @@ impl Metadata for SessionMetadata {
     type Others = ();
     type Reply = ();
     type Signal = ();
-    type State = Out<State>;
+    type State = InOut<StateQuery, StateReply>;
 }
 
-#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 pub enum Action {
-    StartGame,
-    CheckWord { word: String },
-    CheckGameStatus { user: ActorId, init_id: MessageId },
+    /// Starts a game on the target program registered under `variant`,
+    /// or on the default target program when `None`.
+    #[codec(index = 0)]
+    StartGame { variant: Option<String> },
+    #[codec(index = 1)]
+    CheckWord { game_id: GameId, word: String },
+    #[codec(index = 2)]
+    CheckGameStatus { user: ActorId, game_id: GameId },
+    /// Creates a tournament organized by the sender.
+    ///
+    /// The value attached to the message becomes the prize pool.
+    #[codec(index = 3)]
+    CreateTournament(TournamentConfig),
+    /// Registers the sender as a participant while registration is open.
+    #[codec(index = 4)]
+    RegisterForTournament { tournament_id: TournamentId },
+    /// Starts the sender's game for the current round of the tournament.
+    #[codec(index = 5)]
+    PlayTournamentRound { tournament_id: TournamentId },
+    /// Moves the tournament to its next stage. Callable by current program only.
+    #[codec(index = 6)]
+    AdvanceTournament { tournament_id: TournamentId },
+    /// Registers a target program under the given variant name. Callable by admin only.
+    #[codec(index = 7)]
+    AddVariant { name: String, program_id: ActorId },
+    /// Removes the target program registered under the given variant name. Callable by admin only.
+    #[codec(index = 8)]
+    RemoveVariant { name: String },
+    /// Hands the admin role over to `new_admin`. Callable by admin only.
+    #[codec(index = 9)]
+    TransferOwnership { new_admin: ActorId },
+    /// Replaces the default target program used by new games. Callable by admin only.
+    #[codec(index = 10)]
+    SetTargetProgram { program_id: ActorId },
+    /// Stops new games from being started, games in progress can still be finished.
+    /// Callable by admin only.
+    #[codec(index = 11)]
+    Pause,
+    /// Allows new games to be started again. Callable by admin only.
+    #[codec(index = 12)]
+    Unpause,
+    /// Archives up to `limit` finished games into the stats of their players, then schedules
+    /// the next batch while finished games remain. `limit` must be greater than zero.
+    /// Callable by admin and current program only.
+    #[codec(index = 13)]
+    Cleanup { limit: u32 },
+    /// Suspends the timeout of the sender's game in progress, a limited number of times per game.
+    /// The game is lost if it's not resumed within a limited number of blocks.
+    #[codec(index = 14)]
+    PauseGame { game_id: GameId },
+    /// Resumes the sender's paused game with the blocks it had left when it was paused.
+    #[codec(index = 15)]
+    ResumeGame { game_id: GameId },
+    /// Reveals one letter of the sender's game at its position, paid with one of the attempts
+    /// of the game or with the hint fee attached to the message. Not available in tournament games.
+    #[codec(index = 16)]
+    RequestHint {
+        game_id: GameId,
+        payment: HintPayment,
+    },
+    /// Sets the fee of hints paid with value, `None` to only allow hints paid with attempts.
+    /// Callable by admin only.
+    #[codec(index = 17)]
+    SetHintFee { fee: Option<u128> },
+    /// Starts a game like `StartGame` that never times out and is left out of the stats.
+    /// The sender's previous practice game is lost if it's not over yet.
+    #[codec(index = 18)]
+    StartPracticeGame { variant: Option<String> },
+    /// Starts a timed game on the given puzzle of the target program, left out of the stats.
+    /// Puzzles of games that are not over or of tournament rounds being played can't be replayed.
+    #[codec(index = 19)]
+    ReplayPuzzle {
+        variant: Option<String>,
+        puzzle: PuzzleId,
+    },
+    /// Replies with the shareable summary of the sender's completed game.
+    #[codec(index = 20)]
+    ShareResult { game_id: GameId },
+    /// Sends `amount` of the collected hint fees to `to`. Callable by admin only.
+    #[codec(index = 21)]
+    WithdrawFees { to: ActorId, amount: u128 },
+}
+
+/// Kind of a game, chosen when starting it.
+#[derive(Debug, Default, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum GameMode {
+    /// Timed game counted in the stats of the player.
+    #[default]
+    #[codec(index = 0)]
+    Standard,
+    /// Game without timeout, left out of the stats of the player.
+    #[codec(index = 1)]
+    Practice,
+    /// Timed game on a chosen puzzle, left out of the stats of the player.
+    #[codec(index = 2)]
+    Replay,
+}
+
+/// How a hint is paid for.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum HintPayment {
+    /// Consumes one of the attempts of the game.
+    #[codec(index = 0)]
+    Attempt,
+    /// Requires the hint fee to be attached to the message.
+    #[codec(index = 1)]
+    Fee,
 }
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 pub enum GameOverStatus {
+    #[codec(index = 0)]
     Win,
+    #[codec(index = 1)]
     Lose,
 }
 
+impl From<&GameOverStatus> for GameOutcome {
+    fn from(status: &GameOverStatus) -> Self {
+        match status {
+            GameOverStatus::Win => GameOutcome::Win,
+            GameOverStatus::Lose => GameOutcome::Lose,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 /// Represents the various statuses that a game can have.
 pub enum GameStatus {
@@ -40,10 +159,12 @@ pub enum GameStatus {
     /// # Fields
     /// - `correct_positions`: A vector of positions (indices) where the guessed letters match exactly.
     /// - `contained_in_word`: A vector of positions where the guessed letters are present in the word but in different positions.
+    /// - `feedback`: The feedback for the letter at each position of the guess.
     /// - `is_guessed`: A boolean indicating whether the word has been correctly guessed.
     WordChecked {
         correct_positions: Vec<u8>,
         contained_in_word: Vec<u8>,
+        feedback: Vec<LetterFeedback>,
         is_guessed: bool,
     },
     /// The game is ongoing and has not yet reached a conclusion.
@@ -53,35 +174,274 @@ pub enum GameStatus {
     /// # Fields
     /// - `GameOverStatus`: Indicates whether the game ended in a win or a loss.
     Completed(GameOverStatus),
+    /// The target program refused to serve the game.
+    ///
+    /// # Fields
+    /// - `WordleError`: The error replied by the target program.
+    Rejected(WordleError),
+    /// The game is paused by the player and its clock is suspended until it's resumed,
+    /// or until the pause runs out and the game is lost.
+    Paused,
+    /// The game is in the process of revealing a letter as a hint.
+    RevealingLetter,
+    /// The status after a letter has been revealed.
+    ///
+    /// # Fields
+    /// - `position`: The position of the revealed letter in the word.
+    /// - `letter`: The revealed letter.
+    LetterRevealed { position: u8, letter: String },
 }
 
-#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+/// Versioned envelope of the events emitted by the program.
+///
+/// Variants and their codec indexes are never changed or reused, a breaking change
+/// to the events is introduced as a new version next to the previous ones.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
 pub enum Event {
-    GameStarted,
+    #[codec(index = 1)]
+    V1(EventV1),
+    #[codec(index = 2)]
+    V2(EventV2),
+}
+
+impl From<EventV1> for Event {
+    fn from(event: EventV1) -> Self {
+        Event::V1(event)
+    }
+}
+
+impl From<EventV2> for Event {
+    fn from(event: EventV2) -> Self {
+        Event::V2(event)
+    }
+}
+
+/// Events of the first version of the protocol, kept to decode past events.
+///
+/// New variants may only be appended with the next free codec index.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum EventV1 {
+    #[codec(index = 0)]
+    GameStarted { game_id: GameId },
+    #[codec(index = 1)]
+    WordChecked {
+        /// The guess as it was checked, trimmed and lowercased.
+        word: String,
+        correct_positions: Vec<u8>,
+        contained_in_word: Vec<u8>,
+        /// Feedback for the letter at each position of the guess.
+        feedback: Vec<LetterFeedback>,
+    },
+    #[codec(index = 2)]
+    GameOver {
+        game_id: GameId,
+        status: GameOverStatus,
+    },
+    #[codec(index = 3)]
+    TournamentCreated { tournament_id: TournamentId },
+    #[codec(index = 4)]
+    RegisteredForTournament { tournament_id: TournamentId },
+    #[codec(index = 5)]
+    RoundStarted {
+        tournament_id: TournamentId,
+        round: u32,
+    },
+    #[codec(index = 6)]
+    TournamentFinished {
+        tournament_id: TournamentId,
+        place: Option<u32>,
+        prize: u128,
+    },
+    #[codec(index = 7)]
+    VariantAdded { name: String, program_id: ActorId },
+    #[codec(index = 8)]
+    VariantRemoved { name: String },
+    #[codec(index = 9)]
+    OwnershipTransferred {
+        previous_admin: ActorId,
+        new_admin: ActorId,
+    },
+    #[codec(index = 10)]
+    TargetProgramSet { program_id: ActorId },
+    #[codec(index = 11)]
+    Paused,
+    #[codec(index = 12)]
+    Unpaused,
+    #[codec(index = 13)]
+    GamesArchived { count: u32 },
+}
+
+/// Events of the second version of the protocol, emitted by the program.
+///
+/// Starting and checking words tell how many attempts and blocks the game has left.
+///
+/// New variants may only be appended with the next free codec index.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum EventV2 {
+    /// Superseded by [`EventV2::GameStartedWithPuzzle`], kept to decode past events.
+    #[codec(index = 0)]
+    GameStarted {
+        game_id: GameId,
+        /// Block height at which the game times out, zero if it doesn't.
+        deadline: u32,
+        max_attempts: u32,
+    },
+    #[codec(index = 1)]
     WordChecked {
+        /// The guess as it was checked, trimmed and lowercased.
+        word: String,
         correct_positions: Vec<u8>,
         contained_in_word: Vec<u8>,
+        /// Feedback for the letter at each position of the guess.
+        feedback: Vec<LetterFeedback>,
+        attempts_left: u32,
+        /// Blocks left before the game times out, zero if it doesn't.
+        blocks_left: u32,
+    },
+    #[codec(index = 2)]
+    GameOver {
+        game_id: GameId,
+        status: GameOverStatus,
+    },
+    #[codec(index = 3)]
+    TournamentCreated { tournament_id: TournamentId },
+    #[codec(index = 4)]
+    RegisteredForTournament { tournament_id: TournamentId },
+    #[codec(index = 5)]
+    RoundStarted {
+        tournament_id: TournamentId,
+        round: u32,
+    },
+    #[codec(index = 6)]
+    TournamentFinished {
+        tournament_id: TournamentId,
+        place: Option<u32>,
+        prize: u128,
+    },
+    #[codec(index = 7)]
+    VariantAdded { name: String, program_id: ActorId },
+    #[codec(index = 8)]
+    VariantRemoved { name: String },
+    #[codec(index = 9)]
+    OwnershipTransferred {
+        previous_admin: ActorId,
+        new_admin: ActorId,
+    },
+    #[codec(index = 10)]
+    TargetProgramSet { program_id: ActorId },
+    #[codec(index = 11)]
+    Paused,
+    #[codec(index = 12)]
+    Unpaused,
+    #[codec(index = 13)]
+    GamesArchived { count: u32 },
+    #[codec(index = 14)]
+    GamePaused { game_id: GameId, pauses_left: u32 },
+    #[codec(index = 15)]
+    GameResumed {
+        game_id: GameId,
+        /// Block height at which the game now times out.
+        deadline: u32,
+    },
+    #[codec(index = 16)]
+    HintRevealed {
+        game_id: GameId,
+        position: u8,
+        letter: String,
+        attempts_left: u32,
+    },
+    #[codec(index = 17)]
+    HintFeeSet { fee: Option<u128> },
+    #[codec(index = 18)]
+    ResultShared {
+        game_id: GameId,
+        summary: ResultSummary,
+    },
+    #[codec(index = 19)]
+    FeesWithdrawn { to: ActorId, amount: u128 },
+    #[codec(index = 20)]
+    GameStartedWithPuzzle {
+        game_id: GameId,
+        /// The puzzle the game is given, which can be replayed with `ReplayPuzzle`.
+        puzzle: PuzzleId,
+        /// Block height at which the game times out, zero if it doesn't.
+        deadline: u32,
+        max_attempts: u32,
     },
-    GameOver(GameOverStatus),
 }
 
 type SentMessageId = MessageId;
 type OriginalMessageId = MessageId;
 
+/// The delayed `CheckGameStatus` message a game times out with.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct ScheduledTimeout {
+    pub msg_id: MessageId,
+    /// Block height at which the message is due.
+    pub due_at: u32,
+}
+
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 pub struct PlayerInfo {
     pub game_status: GameStatus,
     pub attempts_count: u32,
     pub init_msg_id: MessageId,
+    pub mode: GameMode,
+    /// The Wordle program this game is played on.
+    pub target_program_id: ActorId,
+    /// Block height at which the game was started.
+    pub started_at: u32,
+    /// The tournament round this game is played for, if any.
+    pub tournament_round: Option<TournamentRound>,
+    /// Alphabet the word of the game is made of, known once the game has started.
+    pub alphabet: Alphabet,
+    /// The puzzle the game is given, known once the game has started.
+    pub puzzle: Option<PuzzleId>,
+    /// Best known status of each guessed letter across all guesses, keyed by letter.
+    pub keyboard: BTreeMap<String, LetterFeedback>,
+    /// Gas reserved to end the game on the target program if it times out,
+    /// released as soon as the game is over.
+    pub reservation_id: Option<ReservationId>,
+    /// Pending timeout of the game, `None` once it's over. While the game is paused,
+    /// it's when the pause runs out.
+    pub timeout: Option<ScheduledTimeout>,
+    pub pauses_used: u32,
+    /// Blocks the game had left before timing out when it was paused.
+    pub paused_blocks_left: Option<u32>,
+    /// Letters of the word known at their position, from correct guesses and hints.
+    pub revealed: BTreeMap<u8, String>,
+    /// Feedback of each guess, in order.
+    pub guesses: Vec<GuessFeedback>,
+    pub hints_used: u32,
     msg_ids: (SentMessageId, OriginalMessageId),
 }
 
 impl PlayerInfo {
-    pub fn new(sent_msg_id: SentMessageId, original_msg_id: OriginalMessageId) -> Self {
+    pub fn new(
+        sent_msg_id: SentMessageId,
+        original_msg_id: OriginalMessageId,
+        target_program_id: ActorId,
+        started_at: u32,
+        tournament_round: Option<TournamentRound>,
+    ) -> Self {
         Self {
             game_status: GameStatus::Starting,
             attempts_count: 0,
             init_msg_id: original_msg_id,
+            mode: GameMode::default(),
+            target_program_id,
+            started_at,
+            tournament_round,
+            alphabet: Alphabet::default(),
+            puzzle: None,
+            keyboard: BTreeMap::new(),
+            reservation_id: None,
+            timeout: None,
+            pauses_used: 0,
+            paused_blocks_left: None,
+            revealed: BTreeMap::new(),
+            guesses: Vec::new(),
+            hints_used: 0,
             msg_ids: (sent_msg_id, original_msg_id),
         }
     }
@@ -98,40 +458,434 @@ impl PlayerInfo {
         self.msg_ids = (sent_msg_id, original_msg_id);
     }
 
+    /// Block height at which the game times out, if it's still pending.
+    pub fn times_out_at(&self) -> Option<u32> {
+        self.timeout.map(|timeout| timeout.due_at)
+    }
+
+    pub fn attempts_left(&self) -> u32 {
+        MAX_ATTEMPTS.saturating_sub(self.attempts_count)
+    }
+
+    /// Blocks left at `block_height` before the game times out, zero if it's not pending.
+    pub fn blocks_left(&self, block_height: u32) -> u32 {
+        self.times_out_at()
+            .map_or(0, |due_at| due_at.saturating_sub(block_height))
+    }
+
     pub fn increment_attempt(&mut self) {
         self.attempts_count += 1;
     }
 
+    /// Records the feedback of a guess, keeping the best known status of each letter
+    /// and the letters found at their position.
+    pub fn record_guess(&mut self, word: &str, feedback: &[LetterFeedback]) {
+        for (position, (letter, feedback)) in word.chars().zip(feedback).enumerate() {
+            let status = self.keyboard.entry(letter.to_string()).or_insert(*feedback);
+            *status = (*status).max(*feedback);
+            if *feedback == LetterFeedback::Correct {
+                self.revealed.insert(position as u8, letter.to_string());
+            }
+        }
+        if let Ok(row) = GuessFeedback::try_from(feedback) {
+            self.guesses.push(row);
+        }
+    }
+
+    /// Shareable summary of the game, `None` until it's completed.
+    pub fn result_summary(&self) -> Option<ResultSummary> {
+        let GameStatus::Completed(status) = &self.game_status else {
+            return None;
+        };
+
+        let puzzle = self.puzzle.map_or_else(
+            || "?".into(),
+            |puzzle| format!("{}:{}", puzzle.dictionary_version, puzzle.index),
+        );
+        let score = match status {
+            GameOverStatus::Win => self.attempts_count.to_string(),
+            GameOverStatus::Lose => "X".into(),
+        };
+        let mut text = format!("Wordle {puzzle} {score}/{MAX_ATTEMPTS}");
+        for row in &self.guesses {
+            text.push('\n');
+            text.extend(row.iter().map(|feedback| match feedback {
+                LetterFeedback::Correct => '🟩',
+                LetterFeedback::Present => '🟨',
+                LetterFeedback::Absent => '⬛',
+            }));
+        }
+
+        Some(ResultSummary {
+            puzzle: self.puzzle,
+            status: status.clone(),
+            attempts: self.attempts_count,
+            grid: self.guesses.clone(),
+            text,
+        })
+    }
+
     pub fn is_playing(&self) -> bool {
         matches!(
             self.game_status,
-            GameStatus::CheckingWord | GameStatus::WordChecked { .. } | GameStatus::InProgress
+            GameStatus::CheckingWord
+                | GameStatus::WordChecked { .. }
+                | GameStatus::RevealingLetter
+                | GameStatus::LetterRevealed { .. }
+                | GameStatus::InProgress
+        )
+    }
+
+    /// Whether the game has ended, either completed or rejected by the target program.
+    pub fn is_over(&self) -> bool {
+        matches!(
+            self.game_status,
+            GameStatus::Completed(..) | GameStatus::Rejected(..)
         )
     }
 }
 
+/// Feedback for the letter at each position of a guess.
+pub type GuessFeedback = [LetterFeedback; WORD_LENGTH];
+
+/// Summary of a completed game, rendered the same way for every client.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct ResultSummary {
+    pub puzzle: Option<PuzzleId>,
+    pub status: GameOverStatus,
+    /// Attempts made, including the ones hints were paid with.
+    pub attempts: u32,
+    /// Feedback of each guess, in order.
+    pub grid: Vec<GuessFeedback>,
+    /// The summary as text: the puzzle and score, e.g. `Wordle 1:42 3/5`,
+    /// followed by a line of squares per guess.
+    pub text: String,
+}
+
+/// Kind of a [`GameStatus`], without the data it carries.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum GameStatusKind {
+    #[codec(index = 0)]
+    Starting,
+    #[codec(index = 1)]
+    Started,
+    #[codec(index = 2)]
+    CheckingWord,
+    #[codec(index = 3)]
+    WordChecked,
+    #[codec(index = 4)]
+    InProgress,
+    #[codec(index = 5)]
+    Completed,
+    #[codec(index = 6)]
+    Rejected,
+    #[codec(index = 7)]
+    Paused,
+    #[codec(index = 8)]
+    RevealingLetter,
+    #[codec(index = 9)]
+    LetterRevealed,
+}
+
+impl GameStatus {
+    pub fn kind(&self) -> GameStatusKind {
+        match self {
+            GameStatus::Starting => GameStatusKind::Starting,
+            GameStatus::Started => GameStatusKind::Started,
+            GameStatus::CheckingWord => GameStatusKind::CheckingWord,
+            GameStatus::WordChecked { .. } => GameStatusKind::WordChecked,
+            GameStatus::InProgress => GameStatusKind::InProgress,
+            GameStatus::Completed(..) => GameStatusKind::Completed,
+            GameStatus::Rejected(..) => GameStatusKind::Rejected,
+            GameStatus::Paused => GameStatusKind::Paused,
+            GameStatus::RevealingLetter => GameStatusKind::RevealingLetter,
+            GameStatus::LetterRevealed { .. } => GameStatusKind::LetterRevealed,
+        }
+    }
+}
+
+/// Part of the state to read.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum StateQuery {
+    /// Games of the given player.
+    #[codec(index = 0)]
+    Player(ActorId),
+    /// Players with at least one game that is not over, in ascending order.
+    #[codec(index = 1)]
+    ActivePlayers { offset: u32, limit: u32 },
+    /// Games whose status is of the given kind.
+    #[codec(index = 2)]
+    GamesByStatus(GameStatusKind),
+    /// The given tournament.
+    #[codec(index = 3)]
+    Tournament(TournamentId),
+    /// Settings of the program.
+    #[codec(index = 4)]
+    Config,
+    /// The whole state, meant for off-chain state functions rather than clients.
+    #[codec(index = 5)]
+    Full,
+    /// Shareable summary of the given completed game of the given player,
+    /// kept for the last few games once they are archived.
+    #[codec(index = 6)]
+    ResultSummary { user: ActorId, game_id: GameId },
+}
+
+/// Reply to the [`StateQuery`] of the same name.
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+pub enum StateReply {
+    #[codec(index = 0)]
+    Player(Vec<(GameId, PlayerInfo)>),
+    #[codec(index = 1)]
+    ActivePlayers(Vec<ActorId>),
+    #[codec(index = 2)]
+    GamesByStatus(Vec<(ActorId, GameId)>),
+    #[codec(index = 3)]
+    Tournament(Option<Tournament>),
+    #[codec(index = 4)]
+    Config(Config),
+    #[codec(index = 5)]
+    Full(State),
+    #[codec(index = 6)]
+    ResultSummary(Option<ResultSummary>),
+}
+
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct Config {
+    pub admin: ActorId,
+    pub target_program_id: ActorId,
+    pub variants: BTreeMap<String, ActorId>,
+    pub paused: bool,
+    pub hint_fee: Option<u128>,
+}
+
 #[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
 pub struct State {
+    pub admin: ActorId,
+    pub target_program_id: ActorId,
+    pub variants: BTreeMap<String, ActorId>,
+    pub paused: bool,
+    pub hint_fee: Option<u128>,
+    /// Hint fees collected and not withdrawn yet.
+    pub collected_fees: u128,
+    pub players: BTreeMap<(ActorId, GameId), PlayerInfo>,
+    /// Totals of the finished games that have been archived.
+    pub stats: BTreeMap<ActorId, PlayerStats>,
+    /// Result summaries of the last archived games of each player.
+    pub results: BTreeMap<(ActorId, GameId), ResultSummary>,
+    pub tournaments: BTreeMap<TournamentId, Tournament>,
+}
+
+/// Totals over the finished games of a player.
+#[derive(Debug, Default, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct PlayerStats {
+    pub games_won: u32,
+    pub games_lost: u32,
+    /// Attempts made over all finished games.
+    pub total_attempts: u32,
+    /// Hints revealed over all finished games.
+    pub hints_used: u32,
+}
+
+impl PlayerStats {
+    /// Adds the game to the totals if it has been completed, unless it's a practice game
+    /// or a replay.
+    pub fn record(&mut self, info: &PlayerInfo) {
+        if info.mode != GameMode::Standard {
+            return;
+        }
+        match info.game_status {
+            GameStatus::Completed(GameOverStatus::Win) => self.games_won += 1,
+            GameStatus::Completed(GameOverStatus::Lose) => self.games_lost += 1,
+            _ => return,
+        }
+        self.total_attempts += info.attempts_count;
+        self.hints_used += info.hints_used;
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.games_won + self.games_lost
+    }
+}
+
+pub type TournamentId = u32;
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct TournamentConfig {
+    /// Number of rounds to be played.
+    pub rounds: u32,
+    /// Number of blocks during which players can register.
+    pub registration_duration: u32,
+    /// Number of blocks each round lasts.
+    pub round_duration: u32,
+    /// Percentages of the prize pool awarded to the 1st, 2nd, ... places.
+    /// Whatever is not awarded goes back to the organizer.
+    pub prize_split: Vec<u8>,
+    /// The variant all rounds are played on, the default target program when `None`.
+    pub variant: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct TournamentRound {
+    pub tournament_id: TournamentId,
+    pub round: u32,
+}
+
+impl TournamentRound {
+    /// Key under which the target program keeps the word shared by all games of the round.
+    pub fn word_key(&self) -> u64 {
+        (u64::from(self.tournament_id) << 32) | u64::from(self.round)
+    }
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+/// Represents the stages a tournament goes through.
+pub enum TournamentStage {
+    /// Players can register to the tournament.
+    Registration,
+    /// The given round (starting from 1) is being played.
+    Round(u32),
+    /// All rounds have been played and the prize pool has been distributed.
+    Finished,
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct RoundResult {
+    pub round: u32,
+    pub solved: bool,
+    pub attempts: u32,
+    /// Number of blocks it took to finish the game.
+    pub blocks: u32,
+}
+
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub struct Standing {
+    pub player: ActorId,
+    pub rounds_solved: u32,
+    pub total_attempts: u32,
+    pub total_blocks: u32,
+    pub prize: u128,
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+pub struct Tournament {
+    pub organizer: ActorId,
+    pub config: TournamentConfig,
+    /// Target program the variant of the tournament resolved to when it was created,
+    /// used for all of its rounds.
     pub target_program_id: ActorId,
-    pub players: BTreeMap<ActorId, PlayerInfo>,
+    pub prize_pool: u128,
+    pub stage: TournamentStage,
+    /// Results of the rounds played by each participant.
+    pub participants: BTreeMap<ActorId, Vec<RoundResult>>,
+    /// Final standings, available once the tournament is finished.
+    pub standings: Vec<Standing>,
+    /// Puzzle of each round, known once a game of the round has started.
+    pub puzzles: BTreeMap<u32, PuzzleId>,
+    /// Gas reserved for the delayed messages moving the tournament to its next stages,
+    /// one per stage left to schedule.
+    pub reservations: Vec<ReservationId>,
 }
 
-impl From<WordleEvent> for GameStatus {
-    fn from(event: WordleEvent) -> Self {
+impl Tournament {
+    pub fn new(
+        organizer: ActorId,
+        config: TournamentConfig,
+        target_program_id: ActorId,
+        prize_pool: u128,
+    ) -> Self {
+        Self {
+            organizer,
+            config,
+            target_program_id,
+            prize_pool,
+            stage: TournamentStage::Registration,
+            participants: BTreeMap::new(),
+            standings: Vec::new(),
+            puzzles: BTreeMap::new(),
+            reservations: Vec::new(),
+        }
+    }
+
+    pub fn current_round(&self) -> Option<u32> {
+        match self.stage {
+            TournamentStage::Round(round) => Some(round),
+            _ => None,
+        }
+    }
+
+    pub fn has_played(&self, player: &ActorId, round: u32) -> bool {
+        self.participants
+            .get(player)
+            .is_some_and(|results| results.iter().any(|result| result.round == round))
+    }
+
+    /// Ranks the participants by solved rounds, then by attempts and blocks spent.
+    ///
+    /// Rounds that were not finished count as unsolved with `max_attempts` attempts
+    /// and the whole round duration spent.
+    pub fn compute_standings(&self, max_attempts: u32) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = self
+            .participants
+            .iter()
+            .map(|(player, results)| {
+                let missed = self.config.rounds.saturating_sub(results.len() as u32);
+                results.iter().fold(
+                    Standing {
+                        player: *player,
+                        total_attempts: missed * max_attempts,
+                        total_blocks: missed * self.config.round_duration,
+                        ..Default::default()
+                    },
+                    |mut standing, result| {
+                        standing.rounds_solved += u32::from(result.solved);
+                        standing.total_attempts += result.attempts;
+                        standing.total_blocks += result.blocks;
+                        standing
+                    },
+                )
+            })
+            .collect();
+
+        standings.sort_by(|a, b| {
+            b.rounds_solved
+                .cmp(&a.rounds_solved)
+                .then(a.total_attempts.cmp(&b.total_attempts))
+                .then(a.total_blocks.cmp(&b.total_blocks))
+        });
+
+        for (standing, share) in standings.iter_mut().zip(&self.config.prize_split) {
+            standing.prize = self.prize_pool * u128::from(*share) / 100;
+        }
+
+        standings
+    }
+}
+
+impl TryFrom<WordleEvent> for GameStatus {
+    type Error = WordleEvent;
+
+    fn try_from(event: WordleEvent) -> Result<Self, Self::Error> {
         match event {
-            WordleEvent::GameStarted { .. } => GameStatus::Started,
+            WordleEvent::GameStarted { .. } => Ok(GameStatus::Started),
             WordleEvent::WordChecked {
                 correct_positions,
                 contained_in_word,
+                feedback,
                 ..
             } => {
                 let is_guessed = correct_positions.len() == WORD_LENGTH;
-                GameStatus::WordChecked {
+                Ok(GameStatus::WordChecked {
                     correct_positions,
                     contained_in_word,
+                    feedback,
                     is_guessed,
-                }
+                })
             }
+            WordleEvent::LetterRevealed {
+                position, letter, ..
+            } => Ok(GameStatus::LetterRevealed { position, letter }),
+            event => Err(event),
         }
     }
 }
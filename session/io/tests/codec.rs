@@ -0,0 +1,149 @@
+//! Encoded fixtures of the protocol.
+//!
+//! Deployed front-ends decode these exact bytes: a failing fixture means a breaking change,
+//! which has to go into a new event version instead of modifying an existing one.
+
+use parity_scale_codec::{Decode, Encode};
+use session_io::{Action, Event, EventV1, EventV2, GameOverStatus, LetterFeedback, PuzzleId};
+
+fn assert_fixture<T: Encode + Decode + PartialEq + core::fmt::Debug>(value: T, bytes: &[u8]) {
+    assert_eq!(value.encode(), bytes, "encoding of {value:?} changed");
+    assert_eq!(T::decode(&mut &bytes[..]).unwrap(), value);
+}
+
+#[test]
+fn actions_should_keep_their_encoding() {
+    assert_fixture(Action::StartGame { variant: None }, &[0, 0]);
+    assert_fixture(
+        Action::StartGame {
+            variant: Some("ru".into()),
+        },
+        &[0, 1, 8, b'r', b'u'],
+    );
+    assert_fixture(
+        Action::CheckWord {
+            game_id: 7,
+            word: "house".into(),
+        },
+        &[1, 7, 0, 0, 0, 0, 0, 0, 0, 20, b'h', b'o', b'u', b's', b'e'],
+    );
+    assert_fixture(
+        Action::PlayTournamentRound { tournament_id: 3 },
+        &[5, 3, 0, 0, 0],
+    );
+    assert_fixture(Action::Pause, &[11]);
+    assert_fixture(Action::Unpause, &[12]);
+    assert_fixture(
+        Action::ResumeGame { game_id: 1 },
+        &[15, 1, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_fixture(
+        Action::WithdrawFees {
+            to: [1; 32].into(),
+            amount: 2,
+        },
+        &[
+            21, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    );
+}
+
+#[test]
+fn events_should_keep_their_encoding() {
+    assert_fixture(
+        Event::V1(EventV1::GameStarted { game_id: 7 }),
+        &[1, 0, 7, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_fixture(
+        Event::V1(EventV1::WordChecked {
+            word: "mouse".into(),
+            correct_positions: vec![1, 2, 3, 4],
+            contained_in_word: vec![],
+            feedback: vec![
+                LetterFeedback::Absent,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+            ],
+        }),
+        &[
+            1, 1, 20, b'm', b'o', b'u', b's', b'e', 16, 1, 2, 3, 4, 0, 20, 0, 2, 2, 2, 2,
+        ],
+    );
+    assert_fixture(
+        Event::V1(EventV1::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Lose,
+        }),
+        &[1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    );
+    assert_fixture(
+        Event::V1(EventV1::TournamentFinished {
+            tournament_id: 1,
+            place: Some(2),
+            prize: 5,
+        }),
+        &[
+            1, 6, 1, 0, 0, 0, 1, 2, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    );
+    assert_fixture(Event::V1(EventV1::Unpaused), &[1, 12]);
+}
+
+#[test]
+fn events_v2_should_keep_their_encoding() {
+    assert_fixture(
+        Event::V2(EventV2::GameStarted {
+            game_id: 7,
+            deadline: 300,
+            max_attempts: 5,
+        }),
+        &[2, 0, 7, 0, 0, 0, 0, 0, 0, 0, 44, 1, 0, 0, 5, 0, 0, 0],
+    );
+    assert_fixture(
+        Event::V2(EventV2::GameStartedWithPuzzle {
+            game_id: 7,
+            puzzle: PuzzleId {
+                dictionary_version: 1,
+                index: 42,
+            },
+            deadline: 300,
+            max_attempts: 5,
+        }),
+        &[
+            2, 20, 7, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 42, 0, 0, 0, 44, 1, 0, 0, 5, 0, 0, 0,
+        ],
+    );
+    assert_fixture(
+        Event::V2(EventV2::WordChecked {
+            word: "mouse".into(),
+            correct_positions: vec![1, 2, 3, 4],
+            contained_in_word: vec![],
+            feedback: vec![
+                LetterFeedback::Absent,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+            ],
+            attempts_left: 4,
+            blocks_left: 199,
+        }),
+        &[
+            2, 1, 20, b'm', b'o', b'u', b's', b'e', 16, 1, 2, 3, 4, 0, 20, 0, 2, 2, 2, 2, 4, 0, 0,
+            0, 199, 0, 0, 0,
+        ],
+    );
+    assert_fixture(
+        Event::V2(EventV2::GamesArchived { count: 3 }),
+        &[2, 13, 3, 0, 0, 0],
+    );
+}
+
+#[test]
+fn unknown_event_version_should_not_decode() {
+    // Version 0 was never used, so clients can tell a newer envelope from garbage
+    assert!(Event::decode(&mut &[0, 0, 7, 0, 0, 0, 0, 0, 0, 0][..]).is_err());
+}
@@ -0,0 +1,59 @@
+use session_io::{
+    GameOverStatus, GameStatus, LetterFeedback::*, PlayerInfo, PuzzleId, ResultSummary,
+};
+
+fn player_info() -> PlayerInfo {
+    let mut info = PlayerInfo::new(
+        Default::default(),
+        Default::default(),
+        Default::default(),
+        0,
+        None,
+    );
+    info.puzzle = Some(PuzzleId {
+        dictionary_version: 1,
+        index: 42,
+    });
+    info
+}
+
+#[test]
+fn summary_should_render_won_game() {
+    let mut info = player_info();
+    info.record_guess("mouse", &[Absent, Correct, Correct, Correct, Correct]);
+    info.record_guess("house", &[Correct; 5]);
+    info.attempts_count = 2;
+    info.game_status = GameStatus::Completed(GameOverStatus::Win);
+
+    let summary = info.result_summary().unwrap();
+    assert_eq!(
+        summary,
+        ResultSummary {
+            puzzle: info.puzzle,
+            status: GameOverStatus::Win,
+            attempts: 2,
+            grid: vec![[Absent, Correct, Correct, Correct, Correct], [Correct; 5]],
+            text: "Wordle 1:42 2/5\n⬛🟩🟩🟩🟩\n🟩🟩🟩🟩🟩".into(),
+        }
+    );
+}
+
+#[test]
+fn summary_should_render_lost_game() {
+    let mut info = player_info();
+    info.record_guess("hello", &[Correct, Absent, Absent, Absent, Present]);
+    info.attempts_count = 1;
+    info.game_status = GameStatus::Completed(GameOverStatus::Lose);
+
+    let summary = info.result_summary().unwrap();
+    assert_eq!(summary.text, "Wordle 1:42 X/5\n🟩⬛⬛⬛🟨");
+}
+
+#[test]
+fn summary_should_be_none_until_completed() {
+    let mut info = player_info();
+    info.record_guess("mouse", &[Absent, Correct, Correct, Correct, Correct]);
+    info.game_status = GameStatus::InProgress;
+
+    assert_eq!(info.result_summary(), None);
+}
@@ -2,18 +2,65 @@ pub mod err_msgs {
     pub const GAME_IS_PLAYING: &str = "A game is in progress for this user";
     pub const SEND_FAILED: &str = "Error in sending message";
     pub const SEND_DELAYED_FAILED: &str = "Error in sending delayed message";
+    pub const RESERVE_GAS_FAILED: &str = "Not enough gas to reserve for the game timeout";
+    pub const UNRESERVE_GAS_FAILED: &str = "Error in releasing reserved gas";
     pub const GAME_NOT_FOUND: &str = "Game does not exist for the user";
     pub const GAME_NOT_PLAYABLE: &str = "Game is not available to play";
-    pub const INVALID_WORD_LEN: &str = "Word must be 5 character long";
-    pub const INVALID_WORD_CASE: &str = "Word must be lowercased";
+    pub const INVALID_WORD_LEN: &str = "Word must be 5 letters long";
+    pub const INVALID_WORD_LETTERS: &str = "Word must be made of letters of the game alphabet";
     pub const LOAD_FAILED: &str = "Unable to message's payload";
-    pub const PLAYER_INFO_NOT_FOUND: &str = "Player info does not exist";
     pub const PROGRAM_ONLY: &str = "Callable by current program only";
     pub const RESUME_FAILED: &str = "Error in resuming paused message";
     pub const READ_REPLY_FAILED: &str = "Error in reading replied Message ID";
+    pub const TOURNAMENT_NOT_FOUND: &str = "Tournament does not exist";
+    pub const INVALID_TOURNAMENT_CONFIG: &str = "Rounds and durations must be greater than zero";
+    pub const INVALID_PRIZE_SPLIT: &str = "Prize split must not exceed 100 percent";
+    pub const REGISTRATION_CLOSED: &str = "Tournament registration is closed";
+    pub const ALREADY_REGISTERED: &str = "Player is already registered to the tournament";
+    pub const NOT_A_PARTICIPANT: &str = "Player is not registered to the tournament";
+    pub const ROUND_NOT_IN_PROGRESS: &str = "No tournament round is in progress";
+    pub const ROUND_ALREADY_PLAYED: &str = "Player has already played this round";
+    pub const ADMIN_ONLY: &str = "Callable by admin only";
+    pub const VARIANT_NOT_FOUND: &str = "Variant is not registered";
+    pub const SESSION_PAUSED: &str = "Starting new games is paused";
+    pub const INVALID_CLEANUP_LIMIT: &str = "Cleanup limit must be greater than zero";
+    pub const TARGET_REJECTED: &str = "Target program refused to serve the game";
+    pub const NO_PAUSES_LEFT: &str = "No pauses left for this game";
+    pub const GAME_NOT_PAUSED: &str = "Game is not paused";
+    pub const TOURNAMENT_GAME_NOT_PAUSABLE: &str = "Tournament games can't be paused";
+    pub const GAME_NOT_TIMED: &str = "Game has no timeout to pause";
+    pub const PUZZLE_IN_PLAY: &str = "Puzzle is being played and can't be replayed yet";
+    pub const GAME_NOT_COMPLETED: &str = "Game is not completed";
+    pub const TOURNAMENT_GAME_NO_HINTS: &str = "Hints are not available in tournament games";
+    pub const NOTHING_TO_REVEAL: &str = "Every letter of the word is already known";
+    pub const NO_ATTEMPTS_FOR_HINT: &str = "Not enough attempts left to pay for a hint";
+    pub const INVALID_HINT_FEE: &str = "The hint fee must be attached to the message";
+    pub const UNEXPECTED_HINT_VALUE: &str = "Value is only accepted for hints paid with the fee";
+    pub const INSUFFICIENT_FEES: &str = "Not enough collected fees to withdraw";
 }
 
 pub mod game_rules {
     pub const DELAY_CHECK_STATUS_DURATION: u32 = 200;
-    pub const MAX_ATTEMPTS: u32 = 5;
+    pub const MAX_ATTEMPTS: u32 = wordle_io::MAX_ATTEMPTS;
+    pub const MAX_PAUSES: u32 = 2;
+    /// Number of blocks a game can stay paused before it's lost.
+    pub const MAX_PAUSE_DURATION: u32 = 600;
+}
+
+pub mod gas {
+    /// Gas reserved for the delayed `CheckGameStatus` message of a game.
+    pub const CHECK_STATUS: u64 = 10_000_000_000;
+    /// Gas reserved for ending a timed out game on the target program.
+    pub const END_GAME: u64 = 10_000_000_000;
+    /// Gas reserved for each delayed `AdvanceTournament` message of a tournament.
+    pub const ADVANCE_TOURNAMENT: u64 = 10_000_000_000;
+    /// Number of blocks reservations outlive the timeout of a game by.
+    pub const RESERVATION_MARGIN: u32 = 10;
+}
+
+pub mod cleanup {
+    /// Number of blocks between two batches of archived games.
+    pub const BATCH_DELAY: u32 = 1;
+    /// Number of result summaries of archived games kept for each player.
+    pub const KEPT_RESULTS: usize = 3;
 }
@@ -1,170 +1,718 @@
 #![no_std]
 use consts::*;
-use gstd::{collections::BTreeMap, exec, msg, prelude::*, ActorId, MessageId};
+use gstd::{
+    collections::{BTreeMap, BTreeSet},
+    exec, msg,
+    prelude::*,
+    ActorId,
+};
 use session_io::*;
-use wordle_io::{Action as WordleAction, Event as WordleEvent};
+use wordle_io::{Action as WordleAction, Error as WordleError, Event as WordleEvent};
 
 #[macro_use]
 mod macros;
 pub mod consts;
+mod state;
+mod tournament;
 
 create_inner_state!(SESSION, Session);
 
 struct Session {
+    pub admin: ActorId,
     pub target_program_id: ActorId,
-    pub players: BTreeMap<ActorId, PlayerInfo>,
+    pub variants: BTreeMap<String, ActorId>,
+    pub paused: bool,
+    pub hint_fee: Option<u128>,
+    pub collected_fees: u128,
+    pub players: BTreeMap<(ActorId, GameId), PlayerInfo>,
+    pub stats: BTreeMap<ActorId, PlayerStats>,
+    pub results: BTreeMap<(ActorId, GameId), ResultSummary>,
+    /// Games given each puzzle of a target program, until they're archived.
+    pub puzzles_in_play: BTreeMap<(ActorId, PuzzleId), BTreeSet<(ActorId, GameId)>>,
+    pub tournaments: BTreeMap<TournamentId, Tournament>,
+    pub next_game_id: GameId,
 }
 
 impl Session {
-    pub fn new(target_program_id: ActorId) -> Self {
+    pub fn new(admin: ActorId, target_program_id: ActorId) -> Self {
         Self {
+            admin,
             target_program_id,
+            variants: BTreeMap::new(),
+            paused: false,
+            hint_fee: None,
+            collected_fees: 0,
             players: BTreeMap::new(),
+            stats: BTreeMap::new(),
+            results: BTreeMap::new(),
+            puzzles_in_play: BTreeMap::new(),
+            tournaments: BTreeMap::new(),
+            next_game_id: 0,
         }
     }
 
-    pub fn start_game(&mut self, user: ActorId) {
-        if let Some(player) = self.players.get_mut(&user) {
-            // ensure the game is not progressing
-            assert!(!player.is_playing(), "{}", err_msgs::GAME_IS_PLAYING);
+    pub fn start_game(
+        &mut self,
+        user: ActorId,
+        variant: Option<String>,
+        mode: GameMode,
+        puzzle: Option<PuzzleId>,
+        tournament_round: Option<TournamentRound>,
+    ) {
+        let original_msg_id = msg::id();
+
+        // Reply when resumed after the game initiated by this message has started
+        if let Some((&(_, game_id), player)) = self
+            .games_of_mut(user)
+            .find(|(_, info)| info.init_msg_id == original_msg_id)
+        {
+            match player.game_status {
+                GameStatus::Rejected(..) => panic!("{}", err_msgs::TARGET_REJECTED),
+                // Timed out before the target program replied
+                GameStatus::Completed(..) => panic!("{}", err_msgs::GAME_NOT_PLAYABLE),
+                _ => {}
+            }
 
             if player.game_status == GameStatus::Started {
-                return Self::set_status_and_reply(
-                    player,
-                    GameStatus::InProgress,
-                    Event::GameStarted,
-                );
+                let event = Event::V2(EventV2::GameStartedWithPuzzle {
+                    game_id,
+                    puzzle: player.puzzle.unwrap_or_default(),
+                    deadline: player.times_out_at().unwrap_or_default(),
+                    max_attempts: game_rules::MAX_ATTEMPTS,
+                });
+                return Self::set_status_and_reply(player, GameStatus::InProgress, event);
             }
         }
 
-        // Send `StartGame` message to Wordle program
-        let sent_msg_id = msg::send(self.target_program_id, WordleAction::StartGame { user }, 0)
-            .expect(err_msgs::SEND_FAILED);
-        let original_msg_id = msg::id();
+        assert!(!self.paused, "{}", err_msgs::SESSION_PAUSED);
 
-        self.players
-            .insert(user, PlayerInfo::new(sent_msg_id, original_msg_id));
+        // Rounds of a tournament are played with the program it was created with
+        let target_program_id = match &tournament_round {
+            Some(round) => {
+                self.tournaments
+                    .get(&round.tournament_id)
+                    .expect(err_msgs::TOURNAMENT_NOT_FOUND)
+                    .target_program_id
+            }
+            None => self.target_program_of(variant.as_ref()),
+        };
+        if let Some(puzzle) = puzzle {
+            assert!(
+                !self.is_puzzle_in_play(target_program_id, puzzle),
+                "{}",
+                err_msgs::PUZZLE_IN_PLAY
+            );
+        }
 
-        // Send a delayed message with `CheckGameStatus` action to monitor game's progress
-        msg::send_delayed(
-            exec::program_id(),
-            Action::CheckGameStatus {
+        // Practice games never time out, a player keeps a single one at a time
+        if mode == GameMode::Practice {
+            self.end_practice_games(user);
+        }
+
+        // Finished games of the user are no longer needed
+        let finished_games = self
+            .games_of_mut(user)
+            .filter(|(_, info)| info.is_over())
+            .map(|(key, _)| *key)
+            .collect();
+        self.archive_games(finished_games);
+
+        let game_id = self.next_game_id;
+        self.next_game_id += 1;
+
+        // Send `StartGame` message to Wordle program
+        let word_key = tournament_round.as_ref().map(TournamentRound::word_key);
+        let sent_msg_id = msg::send(
+            target_program_id,
+            WordleAction::StartGame {
                 user,
-                init_id: original_msg_id,
+                game_id,
+                word_key,
+                puzzle,
             },
             0,
-            game_rules::DELAY_CHECK_STATUS_DURATION,
         )
-        .expect(err_msgs::SEND_DELAYED_FAILED);
+        .expect(err_msgs::SEND_FAILED);
+
+        let mut info = PlayerInfo::new(
+            sent_msg_id,
+            original_msg_id,
+            target_program_id,
+            exec::block_height(),
+            tournament_round,
+        );
+        info.mode = mode;
+        // Send a delayed message with `CheckGameStatus` action to monitor game's progress
+        if mode != GameMode::Practice {
+            Self::schedule_timeout(
+                (user, game_id),
+                &mut info,
+                game_rules::DELAY_CHECK_STATUS_DURATION,
+            );
+        }
+        self.players.insert((user, game_id), info);
 
         // Wait for the response
         exec::wait();
     }
 
-    pub fn check_word(&mut self, user: ActorId, word: String) {
-        let player = self.players.get_mut(&user).expect(err_msgs::GAME_NOT_FOUND);
+    pub fn check_word(&mut self, user: ActorId, game_id: GameId, word: String) {
+        let player = self
+            .players
+            .get_mut(&(user, game_id))
+            .expect(err_msgs::GAME_NOT_FOUND);
+
+        if let GameStatus::Rejected(..) = player.game_status {
+            panic!("{}", err_msgs::TARGET_REJECTED);
+        }
 
-        // Ensure the game exists and is in correct status
-        assert!(player.is_playing(), "{}", err_msgs::GAME_NOT_PLAYABLE);
+        // Only the message that sent a check completes it, while it's pending the game
+        // can't be played by other messages
+        let resumed = player.original_msg_id() == msg::id()
+            && matches!(player.game_status, GameStatus::WordChecked { .. });
+        assert!(
+            resumed || player.game_status == GameStatus::InProgress,
+            "{}",
+            err_msgs::GAME_NOT_PLAYABLE
+        );
+
+        // Validate the submitted word is made of 5 letters of the game alphabet, in any case
+        let word = player
+            .alphabet
+            .normalize(&word)
+            .expect(err_msgs::INVALID_WORD_LETTERS);
+        assert!(
+            word.chars().count() == wordle_io::WORD_LENGTH,
+            "{}",
+            err_msgs::INVALID_WORD_LEN
+        );
 
         if let GameStatus::WordChecked {
             correct_positions,
             contained_in_word,
+            feedback,
             is_guessed,
         } = player.game_status.clone()
         {
-            return Self::handle_word_checked(
-                player,
+            player.record_guess(&word, &feedback);
+            player.increment_attempt();
+            let word_checked = Event::V2(EventV2::WordChecked {
+                word,
                 correct_positions,
                 contained_in_word,
+                feedback,
+                attempts_left: player.attempts_left(),
+                blocks_left: player.blocks_left(exec::block_height()),
+            });
+            return Self::handle_word_checked(
+                &mut self.tournaments,
+                (user, game_id),
+                player,
                 is_guessed,
+                word_checked,
             );
         }
 
-        // Validate the submitted word is in lowercase and is 5 character long
+        // Send `CheckWord` message to wordle program
+        let sent_msg_id = msg::send(
+            player.target_program_id,
+            WordleAction::CheckWord {
+                user,
+                game_id,
+                word,
+            },
+            0,
+        )
+        .expect(err_msgs::SEND_FAILED);
+
+        player.set_msg_ids(sent_msg_id, msg::id());
+        player.game_status = GameStatus::CheckingWord;
+
+        exec::wait();
+    }
+
+    pub fn check_game_status(&mut self, user: ActorId, game_id: GameId) {
         assert!(
-            word.len() == wordle_io::WORD_LENGTH,
+            msg::source() == exec::program_id(),
             "{}",
-            err_msgs::INVALID_WORD_LEN
+            err_msgs::PROGRAM_ONLY
+        );
+
+        let Some(info) = self.players.get_mut(&(user, game_id)) else {
+            // ignore when game has ended and been archived
+            return;
+        };
+
+        // ignore when this message no longer times the game out
+        if info.timeout.map(|timeout| timeout.msg_id) != Some(msg::id()) {
+            return;
+        }
+        info.timeout = None;
+
+        if info.is_over() {
+            // ignore when game has ended
+            return;
+        }
+
+        let status = GameOverStatus::Lose;
+        tournament::record_round_result(&mut self.tournaments, user, info, &status);
+        Self::end_game((user, game_id), info, &status);
+        info.game_status = GameStatus::Completed(status.clone());
+        msg::send(user, Event::V2(EventV2::GameOver { game_id, status }), 0)
+            .expect(err_msgs::SEND_FAILED);
+    }
+
+    pub fn pause_game(&mut self, user: ActorId, game_id: GameId) {
+        let info = self
+            .players
+            .get_mut(&(user, game_id))
+            .expect(err_msgs::GAME_NOT_FOUND);
+
+        assert!(
+            info.game_status == GameStatus::InProgress,
+            "{}",
+            err_msgs::GAME_NOT_PLAYABLE
         );
         assert!(
-            word.chars().all(|c| c.is_lowercase()),
+            info.tournament_round.is_none(),
             "{}",
-            err_msgs::INVALID_WORD_CASE
+            err_msgs::TOURNAMENT_GAME_NOT_PAUSABLE
+        );
+        assert!(info.timeout.is_some(), "{}", err_msgs::GAME_NOT_TIMED);
+        assert!(
+            info.pauses_used < game_rules::MAX_PAUSES,
+            "{}",
+            err_msgs::NO_PAUSES_LEFT
         );
 
-        // Send `CheckWord` message to wordle program
+        // The pending timeout is ignored once it fires, the game is lost instead
+        // if it's not resumed in time
+        info.paused_blocks_left = Some(info.blocks_left(exec::block_height()));
+        Self::cancel_timeout(info);
+        Self::schedule_timeout((user, game_id), info, game_rules::MAX_PAUSE_DURATION);
+        info.pauses_used += 1;
+
+        let pauses_left = game_rules::MAX_PAUSES - info.pauses_used;
+        Self::set_status_and_reply(
+            info,
+            GameStatus::Paused,
+            Event::V2(EventV2::GamePaused {
+                game_id,
+                pauses_left,
+            }),
+        )
+    }
+
+    pub fn resume_game(&mut self, user: ActorId, game_id: GameId) {
+        let info = self
+            .players
+            .get_mut(&(user, game_id))
+            .expect(err_msgs::GAME_NOT_FOUND);
+
+        assert!(
+            info.game_status == GameStatus::Paused,
+            "{}",
+            err_msgs::GAME_NOT_PAUSED
+        );
+        let blocks_left = info
+            .paused_blocks_left
+            .take()
+            .expect(err_msgs::GAME_NOT_PAUSED);
+        Self::cancel_timeout(info);
+        Self::schedule_timeout((user, game_id), info, blocks_left);
+
+        let deadline = info.times_out_at().unwrap_or_default();
+        Self::set_status_and_reply(
+            info,
+            GameStatus::InProgress,
+            Event::V2(EventV2::GameResumed { game_id, deadline }),
+        )
+    }
+
+    pub fn request_hint(&mut self, user: ActorId, game_id: GameId, payment: HintPayment) {
+        let hint_fee = self.hint_fee;
+        let player = self
+            .players
+            .get_mut(&(user, game_id))
+            .expect(err_msgs::GAME_NOT_FOUND);
+
+        if let GameStatus::Rejected(..) = player.game_status {
+            panic!("{}", err_msgs::TARGET_REJECTED);
+        }
+
+        // Charge the hint once the letter requested by this message has been revealed,
+        // while it's pending the game can't be played by other messages
+        if player.original_msg_id() == msg::id() {
+            if let GameStatus::LetterRevealed { position, letter } = player.game_status.clone() {
+                match payment {
+                    HintPayment::Attempt => player.increment_attempt(),
+                    HintPayment::Fee => self.collected_fees += msg::value(),
+                }
+                player.hints_used += 1;
+                player.revealed.insert(position, letter.clone());
+                let attempts_left = player.attempts_left();
+                return Self::set_status_and_reply(
+                    player,
+                    GameStatus::InProgress,
+                    Event::V2(EventV2::HintRevealed {
+                        game_id,
+                        position,
+                        letter,
+                        attempts_left,
+                    }),
+                );
+            }
+        }
+
+        assert!(
+            player.game_status == GameStatus::InProgress,
+            "{}",
+            err_msgs::GAME_NOT_PLAYABLE
+        );
+        // Rounds are ranked by attempts, hints would let players buy a better ranking
+        assert!(
+            player.tournament_round.is_none(),
+            "{}",
+            err_msgs::TOURNAMENT_GAME_NO_HINTS
+        );
+        assert!(
+            player.revealed.len() < wordle_io::WORD_LENGTH,
+            "{}",
+            err_msgs::NOTHING_TO_REVEAL
+        );
+        match payment {
+            HintPayment::Attempt => {
+                assert!(msg::value() == 0, "{}", err_msgs::UNEXPECTED_HINT_VALUE);
+                // The last attempt is kept for a guess
+                assert!(
+                    player.attempts_left() > 1,
+                    "{}",
+                    err_msgs::NO_ATTEMPTS_FOR_HINT
+                )
+            }
+            HintPayment::Fee => assert!(
+                hint_fee == Some(msg::value()),
+                "{}",
+                err_msgs::INVALID_HINT_FEE
+            ),
+        }
+
+        // Send `RevealLetter` message to wordle program
         let sent_msg_id = msg::send(
-            self.target_program_id,
-            WordleAction::CheckWord { user, word },
+            player.target_program_id,
+            WordleAction::RevealLetter {
+                user,
+                game_id,
+                known_positions: player.revealed.keys().copied().collect(),
+            },
             0,
         )
         .expect(err_msgs::SEND_FAILED);
 
         player.set_msg_ids(sent_msg_id, msg::id());
-        player.game_status = GameStatus::CheckingWord;
+        player.game_status = GameStatus::RevealingLetter;
 
         exec::wait();
     }
 
-    pub fn check_game_status(&mut self, user: ActorId, init_id: MessageId) {
+    pub fn share_result(&self, user: ActorId, game_id: GameId) {
+        let summary = match self.players.get(&(user, game_id)) {
+            Some(info) => info.result_summary().expect(err_msgs::GAME_NOT_COMPLETED),
+            None => self
+                .results
+                .get(&(user, game_id))
+                .cloned()
+                .expect(err_msgs::GAME_NOT_FOUND),
+        };
+        reply!(Event::V2(EventV2::ResultShared { game_id, summary }))
+    }
+
+    pub fn set_hint_fee(&mut self, fee: Option<u128>) {
+        self.assert_admin();
+        self.hint_fee = fee;
+        reply!(Event::V2(EventV2::HintFeeSet { fee }))
+    }
+
+    /// Sends `amount` of the collected hint fees to `to`, apart from the prize pools
+    /// of tournaments.
+    pub fn withdraw_fees(&mut self, to: ActorId, amount: u128) {
+        self.assert_admin();
         assert!(
-            msg::source() == exec::program_id(),
+            amount <= self.collected_fees,
             "{}",
-            err_msgs::PROGRAM_ONLY
+            err_msgs::INSUFFICIENT_FEES
         );
+        self.collected_fees -= amount;
 
-        let info = self
+        let event = Event::V2(EventV2::FeesWithdrawn { to, amount });
+        msg::send(to, event.clone(), amount).expect(err_msgs::SEND_FAILED);
+        reply!(event)
+    }
+
+    pub fn add_variant(&mut self, name: String, program_id: ActorId) {
+        self.assert_admin();
+        self.variants.insert(name.clone(), program_id);
+        reply!(Event::V2(EventV2::VariantAdded { name, program_id }))
+    }
+
+    pub fn remove_variant(&mut self, name: String) {
+        self.assert_admin();
+        self.variants
+            .remove(&name)
+            .expect(err_msgs::VARIANT_NOT_FOUND);
+        reply!(Event::V2(EventV2::VariantRemoved { name }))
+    }
+
+    pub fn transfer_ownership(&mut self, new_admin: ActorId) {
+        self.assert_admin();
+        let previous_admin = self.admin;
+        self.admin = new_admin;
+        reply!(Event::V2(EventV2::OwnershipTransferred {
+            previous_admin,
+            new_admin
+        }))
+    }
+
+    pub fn set_target_program(&mut self, program_id: ActorId) {
+        self.assert_admin();
+        self.target_program_id = program_id;
+        reply!(Event::V2(EventV2::TargetProgramSet { program_id }))
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.assert_admin();
+        self.paused = paused;
+        reply!(Event::V2(if paused {
+            EventV2::Paused
+        } else {
+            EventV2::Unpaused
+        }))
+    }
+
+    pub fn cleanup(&mut self, limit: u32) {
+        let source = msg::source();
+        assert!(
+            source == self.admin || source == exec::program_id(),
+            "{}",
+            err_msgs::ADMIN_ONLY
+        );
+        assert!(limit > 0, "{}", err_msgs::INVALID_CLEANUP_LIMIT);
+
+        let mut finished_games: Vec<_> = self
             .players
-            .get_mut(&user)
-            .expect(err_msgs::PLAYER_INFO_NOT_FOUND);
+            .iter()
+            .filter(|(_, info)| info.is_over())
+            .map(|(key, _)| *key)
+            .take(limit as usize + 1)
+            .collect();
+        let has_more = finished_games.len() > limit as usize;
+        finished_games.truncate(limit as usize);
+        let count = finished_games.len() as u32;
+        self.archive_games(finished_games);
+
+        if has_more {
+            msg::send_delayed(
+                exec::program_id(),
+                Action::Cleanup { limit },
+                0,
+                cleanup::BATCH_DELAY,
+            )
+            .expect(err_msgs::SEND_DELAYED_FAILED);
+        }
 
-        if let GameStatus::Completed(..) = info.game_status {
-            // ignore when game has ended
-            return;
+        // Batches scheduled by the program itself are not replied to
+        if source == self.admin {
+            reply!(Event::V2(EventV2::GamesArchived { count }))
+        }
+    }
+
+    /// Ends the practice games of `user` that are not over yet as lost.
+    fn end_practice_games(&mut self, user: ActorId) {
+        for (&(_, game_id), info) in self
+            .games_of_mut(user)
+            .filter(|(_, info)| info.mode == GameMode::Practice && !info.is_over())
+        {
+            // A game waiting for the target program can't be ended before it replies
+            assert!(
+                info.game_status == GameStatus::InProgress,
+                "{}",
+                err_msgs::GAME_IS_PLAYING
+            );
+
+            let status = GameOverStatus::Lose;
+            Self::end_game((user, game_id), info, &status);
+            info.game_status = GameStatus::Completed(status.clone());
+            msg::send(user, Event::V2(EventV2::GameOver { game_id, status }), 0)
+                .expect(err_msgs::SEND_FAILED);
+        }
+    }
+
+    /// Removes the given finished games, adding them to the stats of their players
+    /// and keeping their result summaries.
+    fn archive_games(&mut self, keys: Vec<(ActorId, GameId)>) {
+        for key in keys {
+            if let Some(info) = self.players.remove(&key) {
+                if let Some(puzzle) = info.puzzle {
+                    let puzzle_key = (info.target_program_id, puzzle);
+                    if let Some(games) = self.puzzles_in_play.get_mut(&puzzle_key) {
+                        games.remove(&key);
+                        if games.is_empty() {
+                            self.puzzles_in_play.remove(&puzzle_key);
+                        }
+                    }
+                }
+                self.stats.entry(key.0).or_default().record(&info);
+                if let Some(summary) = info.result_summary() {
+                    self.keep_result(key, summary);
+                }
+            }
+        }
+    }
+
+    /// Keeps the result summary of an archived game, dropping the oldest ones of the player
+    /// beyond `KEPT_RESULTS`.
+    fn keep_result(&mut self, (user, game_id): (ActorId, GameId), summary: ResultSummary) {
+        self.results.insert((user, game_id), summary);
+        let kept: Vec<_> = self
+            .results
+            .range((user, GameId::MIN)..=(user, GameId::MAX))
+            .map(|(key, _)| *key)
+            .collect();
+        for key in kept
+            .iter()
+            .take(kept.len().saturating_sub(cleanup::KEPT_RESULTS))
+        {
+            self.results.remove(key);
         }
+    }
+
+    /// Whether the puzzle of the target program is given to a game that is not over
+    /// or to a tournament round being played, whose answer a replay would reveal.
+    fn is_puzzle_in_play(&self, target_program_id: ActorId, puzzle: PuzzleId) -> bool {
+        self.puzzles_in_play
+            .get(&(target_program_id, puzzle))
+            .is_some_and(|games| {
+                games
+                    .iter()
+                    .any(|key| self.players.get(key).is_some_and(|info| !info.is_over()))
+            })
+            || self.tournaments.values().any(|tournament| {
+                tournament.target_program_id == target_program_id
+                    && tournament
+                        .current_round()
+                        .and_then(|round| tournament.puzzles.get(&round))
+                        == Some(&puzzle)
+            })
+    }
 
-        if init_id == info.init_msg_id {
-            let game_over_status = GameOverStatus::Lose;
-            info.game_status = GameStatus::Completed(game_over_status.clone());
-            msg::send(user, Event::GameOver(game_over_status), 0).expect(err_msgs::SEND_FAILED);
+    fn assert_admin(&self) {
+        assert!(msg::source() == self.admin, "{}", err_msgs::ADMIN_ONLY);
+    }
+
+    fn target_program_of(&self, variant: Option<&String>) -> ActorId {
+        match variant {
+            Some(name) => *self.variants.get(name).expect(err_msgs::VARIANT_NOT_FOUND),
+            None => self.target_program_id,
         }
     }
 
+    fn games_of_mut(
+        &mut self,
+        user: ActorId,
+    ) -> impl Iterator<Item = (&(ActorId, GameId), &mut PlayerInfo)> {
+        self.players
+            .range_mut((user, GameId::MIN)..=(user, GameId::MAX))
+    }
+
     fn handle_word_checked(
+        tournaments: &mut BTreeMap<TournamentId, Tournament>,
+        key: (ActorId, GameId),
         player_info: &mut PlayerInfo,
-        correct_positions: Vec<u8>,
-        contained_in_word: Vec<u8>,
         is_guessed: bool,
+        word_checked: Event,
     ) {
-        player_info.increment_attempt();
-
         if is_guessed {
-            return Session::complete_game(player_info, GameOverStatus::Win);
+            return Session::complete_game(tournaments, key, player_info, GameOverStatus::Win);
         }
 
         if player_info.attempts_count == game_rules::MAX_ATTEMPTS {
-            return Session::complete_game(player_info, GameOverStatus::Lose);
+            return Session::complete_game(tournaments, key, player_info, GameOverStatus::Lose);
         }
 
-        Self::set_status_and_reply(
-            player_info,
-            GameStatus::InProgress,
-            Event::WordChecked {
-                correct_positions,
-                contained_in_word,
-            },
-        )
+        Self::set_status_and_reply(player_info, GameStatus::InProgress, word_checked)
     }
 
-    fn complete_game(info: &mut PlayerInfo, status: GameOverStatus) {
+    fn complete_game(
+        tournaments: &mut BTreeMap<TournamentId, Tournament>,
+        (user, game_id): (ActorId, GameId),
+        info: &mut PlayerInfo,
+        status: GameOverStatus,
+    ) {
+        tournament::record_round_result(tournaments, user, info, &status);
+        // The game ends before its timeout, this message pays for ending it instead
+        Self::cancel_timeout(info);
+        Self::end_game((user, game_id), info, &status);
         Self::set_status_and_reply(
             info,
             GameStatus::Completed(status.clone()),
-            Event::GameOver(status),
+            Event::V2(EventV2::GameOver { game_id, status }),
         )
     }
 
+    /// Reserves gas for the timeout of the game and schedules it `delay` blocks from now,
+    /// instead of relying on the gas left to this message.
+    fn schedule_timeout((user, game_id): (ActorId, GameId), info: &mut PlayerInfo, delay: u32) {
+        let reservation_duration = delay + gas::RESERVATION_MARGIN;
+        let check_status_reservation = exec::reserve_gas(gas::CHECK_STATUS, reservation_duration)
+            .expect(err_msgs::RESERVE_GAS_FAILED);
+        let end_game_reservation = exec::reserve_gas(gas::END_GAME, reservation_duration)
+            .expect(err_msgs::RESERVE_GAS_FAILED);
+
+        let msg_id = msg::send_delayed_from_reservation(
+            check_status_reservation,
+            exec::program_id(),
+            Action::CheckGameStatus { user, game_id },
+            0,
+            delay,
+        )
+        .expect(err_msgs::SEND_DELAYED_FAILED);
+
+        info.reservation_id = Some(end_game_reservation);
+        info.timeout = Some(ScheduledTimeout {
+            msg_id,
+            due_at: exec::block_height() + delay,
+        });
+    }
+
+    /// Forgets the pending timeout of the game and releases the gas reserved to end it.
+    fn cancel_timeout(info: &mut PlayerInfo) {
+        info.timeout = None;
+        if let Some(reservation_id) = info.reservation_id.take() {
+            exec::unreserve_gas(reservation_id).expect(err_msgs::UNRESERVE_GAS_FAILED);
+        }
+    }
+
+    /// Lets the target program forget the word of a finished game,
+    /// using the gas reserved for the game if it's still there.
+    fn end_game(
+        (user, game_id): (ActorId, GameId),
+        info: &mut PlayerInfo,
+        status: &GameOverStatus,
+    ) {
+        let action = WordleAction::EndGame {
+            user,
+            game_id,
+            outcome: status.into(),
+        };
+        match info.reservation_id.take() {
+            Some(reservation_id) => {
+                msg::send_from_reservation(reservation_id, info.target_program_id, action, 0)
+            }
+            None => msg::send(info.target_program_id, action, 0),
+        }
+        .expect(err_msgs::SEND_FAILED);
+    }
+
     fn set_status_and_reply(info: &mut PlayerInfo, status: GameStatus, event: Event) {
         info.game_status = status;
         reply!(event)
@@ -174,7 +722,7 @@ impl Session {
 #[no_mangle]
 extern "C" fn init() {
     let target_program_id = msg::load().expect(err_msgs::LOAD_FAILED);
-    unsafe { init_inner_state(Session::new(target_program_id)) }
+    unsafe { init_inner_state(Session::new(msg::source(), target_program_id)) }
 }
 
 #[no_mangle]
@@ -183,9 +731,40 @@ extern "C" fn handle() {
     let session = get_inner_state_mut();
 
     match action {
-        Action::StartGame => session.start_game(msg::source()),
-        Action::CheckWord { word } => session.check_word(msg::source(), word),
-        Action::CheckGameStatus { user, init_id } => session.check_game_status(user, init_id),
+        Action::StartGame { variant } => {
+            session.start_game(msg::source(), variant, GameMode::Standard, None, None)
+        }
+        Action::StartPracticeGame { variant } => {
+            session.start_game(msg::source(), variant, GameMode::Practice, None, None)
+        }
+        Action::ReplayPuzzle { variant, puzzle } => {
+            session.start_game(msg::source(), variant, GameMode::Replay, Some(puzzle), None)
+        }
+        Action::CheckWord { game_id, word } => session.check_word(msg::source(), game_id, word),
+        Action::CheckGameStatus { user, game_id } => session.check_game_status(user, game_id),
+        Action::CreateTournament(config) => session.create_tournament(msg::source(), config),
+        Action::RegisterForTournament { tournament_id } => {
+            session.register_for_tournament(msg::source(), tournament_id)
+        }
+        Action::PlayTournamentRound { tournament_id } => {
+            session.play_tournament_round(msg::source(), tournament_id)
+        }
+        Action::AdvanceTournament { tournament_id } => session.advance_tournament(tournament_id),
+        Action::AddVariant { name, program_id } => session.add_variant(name, program_id),
+        Action::RemoveVariant { name } => session.remove_variant(name),
+        Action::TransferOwnership { new_admin } => session.transfer_ownership(new_admin),
+        Action::SetTargetProgram { program_id } => session.set_target_program(program_id),
+        Action::Pause => session.set_paused(true),
+        Action::Unpause => session.set_paused(false),
+        Action::Cleanup { limit } => session.cleanup(limit),
+        Action::PauseGame { game_id } => session.pause_game(msg::source(), game_id),
+        Action::ResumeGame { game_id } => session.resume_game(msg::source(), game_id),
+        Action::RequestHint { game_id, payment } => {
+            session.request_hint(msg::source(), game_id, payment)
+        }
+        Action::SetHintFee { fee } => session.set_hint_fee(fee),
+        Action::ShareResult { game_id } => session.share_result(msg::source(), game_id),
+        Action::WithdrawFees { to, amount } => session.withdraw_fees(to, amount),
     }
 }
 
@@ -195,23 +774,72 @@ extern "C" fn handle_reply() {
 
     let session = get_inner_state_mut();
 
-    let reply_message = msg::load::<WordleEvent>().expect(err_msgs::LOAD_FAILED);
+    let reply_message =
+        msg::load::<Result<WordleEvent, WordleError>>().expect(err_msgs::LOAD_FAILED);
 
-    let user: ActorId = reply_message.clone().into();
-
-    let player_info = session
-        .players
-        .get(&user)
-        .expect(err_msgs::PLAYER_INFO_NOT_FOUND);
+    let key = match &reply_message {
+        Ok(event) => event.game(),
+        // Errors don't carry the game, look it up by the message being replied to
+        Err(_) => session
+            .players
+            .iter()
+            .find(|(_, info)| info.sent_msg_id() == reply_message_id)
+            .map(|(key, _)| *key),
+    };
+    let Some(key) = key else {
+        return;
+    };
+
+    // The game may have been archived since, e.g. when the target program confirms its end
+    let Some(player_info) = session.players.get(&key) else {
+        return;
+    };
 
     let sent_message_id = player_info.sent_msg_id();
     let original_message_id = player_info.original_msg_id();
+    let tournament_round = player_info.tournament_round;
+    let target_program_id = player_info.target_program_id;
 
     if reply_message_id == sent_message_id {
-        let game_status: GameStatus = reply_message.into();
-        session.players.entry(user).and_modify(|info| {
+        // The game may have timed out while the target program was handling the request,
+        // its outcome stands and the waiting message fails
+        if player_info.is_over() {
+            exec::wake(original_message_id).expect(err_msgs::RESUME_FAILED);
+            return;
+        }
+
+        let started = match &reply_message {
+            Ok(WordleEvent::GameStarted {
+                alphabet, puzzle, ..
+            }) => Some((*alphabet, *puzzle)),
+            _ => None,
+        };
+        let game_status = match reply_message.map(GameStatus::try_from) {
+            Ok(Ok(game_status)) => game_status,
+            Ok(Err(_)) => return,
+            Err(error) => GameStatus::Rejected(error),
+        };
+        session.players.entry(key).and_modify(|info| {
+            // A rejected game is over, the gas reserved for its timeout is no longer needed
+            if let GameStatus::Rejected(..) = game_status {
+                Session::cancel_timeout(info);
+            }
             info.game_status = game_status;
+            if let Some((alphabet, puzzle)) = started {
+                info.alphabet = alphabet;
+                info.puzzle = Some(puzzle);
+            }
         });
+        if let Some((_, puzzle)) = started {
+            session
+                .puzzles_in_play
+                .entry((target_program_id, puzzle))
+                .or_default()
+                .insert(key);
+        }
+        if let (Some((_, puzzle)), Some(round)) = (started, tournament_round) {
+            tournament::record_round_puzzle(&mut session.tournaments, round, puzzle);
+        }
 
         exec::wake(original_message_id).expect(err_msgs::RESUME_FAILED);
     }
@@ -219,15 +847,23 @@ extern "C" fn handle_reply() {
 
 #[no_mangle]
 extern "C" fn state() {
-    let state: State = get_inner_state().into();
-    reply!(state)
+    let query = msg::load::<StateQuery>().expect(err_msgs::LOAD_FAILED);
+    reply!(get_inner_state().query(query))
 }
 
 impl From<Session> for State {
     fn from(value: Session) -> Self {
         Self {
+            admin: value.admin,
             target_program_id: value.target_program_id,
+            variants: value.variants.clone(),
+            paused: value.paused,
+            hint_fee: value.hint_fee,
+            collected_fees: value.collected_fees,
             players: value.players.clone(),
+            stats: value.stats.clone(),
+            results: value.results.clone(),
+            tournaments: value.tournaments.clone(),
         }
     }
 }
@@ -0,0 +1,52 @@
+use crate::*;
+
+impl Session {
+    pub fn query(self, query: StateQuery) -> StateReply {
+        match query {
+            StateQuery::Player(user) => StateReply::Player(
+                self.players
+                    .range((user, GameId::MIN)..=(user, GameId::MAX))
+                    .map(|(&(_, game_id), info)| (game_id, info.clone()))
+                    .collect(),
+            ),
+            StateQuery::ActivePlayers { offset, limit } => {
+                // Games are ordered by player, so a player's games are next to each other
+                let mut previous = None;
+                StateReply::ActivePlayers(
+                    self.players
+                        .iter()
+                        .filter(|(_, info)| !info.is_over())
+                        .map(|(&(user, _), _)| user)
+                        .filter(|user| previous.replace(*user) != Some(*user))
+                        .skip(offset as usize)
+                        .take(limit as usize)
+                        .collect(),
+                )
+            }
+            StateQuery::GamesByStatus(kind) => StateReply::GamesByStatus(
+                self.players
+                    .iter()
+                    .filter(|(_, info)| info.game_status.kind() == kind)
+                    .map(|(key, _)| *key)
+                    .collect(),
+            ),
+            StateQuery::Tournament(tournament_id) => {
+                StateReply::Tournament(self.tournaments.get(&tournament_id).cloned())
+            }
+            StateQuery::Config => StateReply::Config(Config {
+                admin: self.admin,
+                target_program_id: self.target_program_id,
+                variants: self.variants,
+                paused: self.paused,
+                hint_fee: self.hint_fee,
+            }),
+            StateQuery::Full => StateReply::Full(self.into()),
+            StateQuery::ResultSummary { user, game_id } => {
+                StateReply::ResultSummary(match self.players.get(&(user, game_id)) {
+                    Some(info) => info.result_summary(),
+                    None => self.results.get(&(user, game_id)).cloned(),
+                })
+            }
+        }
+    }
+}
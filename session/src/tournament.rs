@@ -0,0 +1,264 @@
+use crate::*;
+
+impl Session {
+    pub fn create_tournament(&mut self, organizer: ActorId, config: TournamentConfig) {
+        assert!(
+            config.rounds > 0 && config.registration_duration > 0 && config.round_duration > 0,
+            "{}",
+            err_msgs::INVALID_TOURNAMENT_CONFIG
+        );
+        assert!(
+            config
+                .prize_split
+                .iter()
+                .map(|share| u32::from(*share))
+                .sum::<u32>()
+                <= 100,
+            "{}",
+            err_msgs::INVALID_PRIZE_SPLIT
+        );
+        // Rounds keep being played with the same program,
+        // even if the variant is changed or removed meanwhile
+        let target_program_id = self.target_program_of(config.variant.as_ref());
+
+        // Every stage transition is funded upfront, so the tournament can't get stuck
+        // for lack of gas once it's created
+        let duration = config
+            .round_duration
+            .saturating_mul(config.rounds)
+            .saturating_add(config.registration_duration)
+            .saturating_add(gas::RESERVATION_MARGIN);
+        let reservations = (0..=config.rounds)
+            .map(|_| {
+                exec::reserve_gas(gas::ADVANCE_TOURNAMENT, duration)
+                    .expect(err_msgs::RESERVE_GAS_FAILED)
+            })
+            .collect();
+
+        let tournament_id = self.tournaments.len() as TournamentId;
+        let registration_duration = config.registration_duration;
+        let mut tournament = Tournament::new(organizer, config, target_program_id, msg::value());
+        tournament.reservations = reservations;
+        schedule_advance(tournament_id, &mut tournament, registration_duration);
+        self.tournaments.insert(tournament_id, tournament);
+
+        reply!(Event::V2(EventV2::TournamentCreated { tournament_id }))
+    }
+
+    pub fn register_for_tournament(&mut self, user: ActorId, tournament_id: TournamentId) {
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .expect(err_msgs::TOURNAMENT_NOT_FOUND);
+
+        assert!(
+            tournament.stage == TournamentStage::Registration,
+            "{}",
+            err_msgs::REGISTRATION_CLOSED
+        );
+        assert!(
+            tournament.participants.insert(user, Vec::new()).is_none(),
+            "{}",
+            err_msgs::ALREADY_REGISTERED
+        );
+
+        reply!(Event::V2(EventV2::RegisteredForTournament {
+            tournament_id
+        }))
+    }
+
+    pub fn play_tournament_round(&mut self, user: ActorId, tournament_id: TournamentId) {
+        let tournament = self
+            .tournaments
+            .get(&tournament_id)
+            .expect(err_msgs::TOURNAMENT_NOT_FOUND);
+
+        assert!(
+            tournament.participants.contains_key(&user),
+            "{}",
+            err_msgs::NOT_A_PARTICIPANT
+        );
+        let round = tournament
+            .current_round()
+            .expect(err_msgs::ROUND_NOT_IN_PROGRESS);
+        assert!(
+            !tournament.has_played(&user, round),
+            "{}",
+            err_msgs::ROUND_ALREADY_PLAYED
+        );
+
+        // Ensure the round is not being played in another game,
+        // except for the one started by this message
+        let tournament_round = Some(TournamentRound {
+            tournament_id,
+            round,
+        });
+        let original_msg_id = msg::id();
+        assert!(
+            !self.games_of_mut(user).any(|(_, info)| {
+                info.tournament_round == tournament_round
+                    && info.init_msg_id != original_msg_id
+                    && !info.is_over()
+            }),
+            "{}",
+            err_msgs::GAME_IS_PLAYING
+        );
+
+        self.start_game(user, None, GameMode::Standard, None, tournament_round)
+    }
+
+    pub fn advance_tournament(&mut self, tournament_id: TournamentId) {
+        assert!(
+            msg::source() == exec::program_id(),
+            "{}",
+            err_msgs::PROGRAM_ONLY
+        );
+
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .expect(err_msgs::TOURNAMENT_NOT_FOUND);
+
+        // The word of the round that is over is no longer needed by the target program
+        if let TournamentStage::Round(round) = tournament.stage {
+            let round = TournamentRound {
+                tournament_id,
+                round,
+            };
+            msg::send(
+                tournament.target_program_id,
+                WordleAction::ForgetWord {
+                    word_key: round.word_key(),
+                },
+                0,
+            )
+            .expect(err_msgs::SEND_FAILED);
+        }
+
+        let next_round = match tournament.stage {
+            TournamentStage::Registration if !tournament.participants.is_empty() => 1,
+            TournamentStage::Round(round) if round < tournament.config.rounds => round + 1,
+            TournamentStage::Finished => return,
+            _ => return finish(tournament_id, tournament),
+        };
+
+        tournament.stage = TournamentStage::Round(next_round);
+        for participant in tournament.participants.keys() {
+            msg::send(
+                *participant,
+                Event::V2(EventV2::RoundStarted {
+                    tournament_id,
+                    round: next_round,
+                }),
+                0,
+            )
+            .expect(err_msgs::SEND_FAILED);
+        }
+
+        let round_duration = tournament.config.round_duration;
+        schedule_advance(tournament_id, tournament, round_duration);
+    }
+}
+
+/// Records the outcome of a finished game if it was played for a round that is still running.
+pub fn record_round_result(
+    tournaments: &mut BTreeMap<TournamentId, Tournament>,
+    user: ActorId,
+    info: &PlayerInfo,
+    status: &GameOverStatus,
+) {
+    let Some(TournamentRound {
+        tournament_id,
+        round,
+    }) = info.tournament_round
+    else {
+        return;
+    };
+
+    let Some(tournament) = tournaments.get_mut(&tournament_id) else {
+        return;
+    };
+
+    if tournament.current_round() != Some(round) {
+        return;
+    }
+
+    if let Some(results) = tournament.participants.get_mut(&user) {
+        results.push(RoundResult {
+            round,
+            solved: *status == GameOverStatus::Win,
+            attempts: info.attempts_count,
+            blocks: exec::block_height() - info.started_at,
+        });
+    }
+}
+
+/// Records the puzzle the games of the round are given, once the first one has started.
+pub fn record_round_puzzle(
+    tournaments: &mut BTreeMap<TournamentId, Tournament>,
+    round: TournamentRound,
+    puzzle: PuzzleId,
+) {
+    if let Some(tournament) = tournaments.get_mut(&round.tournament_id) {
+        tournament.puzzles.entry(round.round).or_insert(puzzle);
+    }
+}
+
+/// Schedules the next stage of the tournament `delay` blocks from now,
+/// with the gas reserved for it when the tournament was created.
+fn schedule_advance(tournament_id: TournamentId, tournament: &mut Tournament, delay: u32) {
+    let action = Action::AdvanceTournament { tournament_id };
+    match tournament.reservations.pop() {
+        Some(reservation_id) => {
+            msg::send_delayed_from_reservation(reservation_id, exec::program_id(), action, 0, delay)
+        }
+        None => msg::send_delayed(exec::program_id(), action, 0, delay),
+    }
+    .expect(err_msgs::SEND_DELAYED_FAILED);
+}
+
+fn finish(tournament_id: TournamentId, tournament: &mut Tournament) {
+    tournament.standings = tournament.compute_standings(game_rules::MAX_ATTEMPTS);
+    tournament.stage = TournamentStage::Finished;
+
+    // Stages that are skipped, e.g. when nobody registered, leave their gas unused
+    for reservation_id in tournament.reservations.drain(..) {
+        exec::unreserve_gas(reservation_id).expect(err_msgs::UNRESERVE_GAS_FAILED);
+    }
+
+    // A prize that can't be transferred, e.g. one below the existential deposit, goes back
+    // to the organizer rather than keeping the tournament from finishing
+    let mut remainder = tournament.prize_pool;
+    for (place, standing) in tournament.standings.iter_mut().enumerate() {
+        let place = Some(place as u32 + 1);
+        if !send_prize(standing.player, tournament_id, place, standing.prize) {
+            standing.prize = 0;
+            send_prize(standing.player, tournament_id, place, 0);
+        }
+        remainder -= standing.prize;
+    }
+
+    // Return whatever is left of the prize pool to the organizer,
+    // dust that can't be transferred stays with the program
+    send_prize(tournament.organizer, tournament_id, None, remainder);
+}
+
+/// Notifies the recipient that the tournament is finished, with their prize attached.
+/// Returns whether the message could be sent.
+fn send_prize(
+    recipient: ActorId,
+    tournament_id: TournamentId,
+    place: Option<u32>,
+    prize: u128,
+) -> bool {
+    msg::send(
+        recipient,
+        Event::V2(EventV2::TournamentFinished {
+            tournament_id,
+            place,
+            prize,
+        }),
+        prize,
+    )
+    .is_ok()
+}
@@ -0,0 +1,68 @@
+#![no_std]
+use gmeta::metawasm;
+use gstd::{collections::BTreeMap, prelude::*, ActorId};
+use session_io::{GameId, GameStatus, PlayerInfo, PlayerStats, StateReply};
+
+#[metawasm]
+pub mod metafns {
+    /// Reply of the program to `StateQuery::Full`.
+    pub type State = StateReply;
+
+    /// Status of the given game, `None` if there is no such game.
+    pub fn player_status(state: State, user: ActorId, game_id: GameId) -> Option<GameStatus> {
+        full(state)
+            .players
+            .get(&(user, game_id))
+            .map(|info| info.game_status.clone())
+    }
+
+    /// Block height at which the given game times out, `None` if it's not pending.
+    pub fn game_timeout(state: State, user: ActorId, game_id: GameId) -> Option<u32> {
+        full(state)
+            .players
+            .get(&(user, game_id))
+            .and_then(PlayerInfo::times_out_at)
+    }
+
+    pub fn player_stats(state: State, user: ActorId) -> PlayerStats {
+        stats_by_player(state).remove(&user).unwrap_or_default()
+    }
+
+    /// The `count` players with the most games won, fewer attempts first on a tie.
+    pub fn leaderboard_top(state: State, count: u32) -> Vec<(ActorId, PlayerStats)> {
+        let mut leaderboard: Vec<_> = stats_by_player(state)
+            .into_iter()
+            .filter(|(_, stats)| stats.games_played() > 0)
+            .collect();
+        leaderboard
+            .sort_by_key(|(_, stats)| (core::cmp::Reverse(stats.games_won), stats.total_attempts));
+        leaderboard.truncate(count as usize);
+        leaderboard
+    }
+
+    /// Number of games that have started and are not over yet, paused ones included.
+    pub fn games_in_progress_count(state: State) -> u32 {
+        full(state)
+            .players
+            .values()
+            .filter(|info| info.is_playing() || info.game_status == GameStatus::Paused)
+            .count() as u32
+    }
+}
+
+fn full(reply: StateReply) -> session_io::State {
+    match reply {
+        StateReply::Full(state) => state,
+        _ => panic!("State functions must be read with `StateQuery::Full`"),
+    }
+}
+
+/// Stats of archived games together with the finished games not archived yet.
+fn stats_by_player(reply: StateReply) -> BTreeMap<ActorId, PlayerStats> {
+    let state = full(reply);
+    let mut stats = state.stats;
+    for (&(user, _), info) in &state.players {
+        stats.entry(user).or_default().record(info);
+    }
+    stats
+}
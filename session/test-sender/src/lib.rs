@@ -0,0 +1,12 @@
+//! Forwards messages with a given gas limit, standing in for players whose messages
+//! carry little gas in the tests of the session program.
+#![no_std]
+use gstd::{msg, prelude::*, ActorId};
+
+#[no_mangle]
+extern "C" fn handle() {
+    let (destination, payload, gas_limit): (ActorId, Vec<u8>, u64) =
+        msg::load().expect("Unable to decode the message to forward");
+    msg::send_bytes_with_gas(destination, payload, gas_limit, 0)
+        .expect("Unable to forward the message");
+}
@@ -0,0 +1,148 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::err_msgs::{ADMIN_ONLY, SESSION_PAUSED};
+use session_io::{Action, Config, Event, EventV2, GameStatus};
+use utils::*;
+
+const NEW_ADMIN: u64 = 4;
+const NEW_TARGET_PROGRAM: u64 = 5;
+
+#[test]
+fn transfer_ownership_should_work() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: Admin transfers the ownership
+    let result = proxy_program.send(
+        USER,
+        Action::TransferOwnership {
+            new_admin: NEW_ADMIN.into(),
+        },
+    );
+
+    // Then:
+    // - OwnershipTransferred event is emitted
+    // - Only the new admin can perform admin actions
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::OwnershipTransferred {
+            previous_admin: USER.into(),
+            new_admin: NEW_ADMIN.into(),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let Config { admin, .. } = config_of(&proxy_program);
+    assert_eq!(admin, NEW_ADMIN.into());
+
+    let result = proxy_program.send(USER, Action::Pause);
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(ADMIN_ONLY));
+    assert!(result.main_failed() && result.contains(&log));
+
+    let result = proxy_program.send(NEW_ADMIN, Action::Pause);
+    assert!(!result.main_failed());
+}
+
+#[test]
+fn set_target_program_should_apply_to_new_games() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+    init_target_program(&system, NEW_TARGET_PROGRAM);
+
+    // Given: A game is in progress on the current target program
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // When: Admin replaces the target program
+    let result = proxy_program.send(
+        USER,
+        Action::SetTargetProgram {
+            program_id: NEW_TARGET_PROGRAM.into(),
+        },
+    );
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::TargetProgramSet {
+            program_id: NEW_TARGET_PROGRAM.into(),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    // Then:
+    // - New games are played on the new target program
+    // - The game in progress keeps its target program and can be finished
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let Config {
+        target_program_id, ..
+    } = config_of(&proxy_program);
+    let games = games_of(&proxy_program, USER);
+    assert_eq!(target_program_id, NEW_TARGET_PROGRAM.into());
+    let info = games.get(&0).unwrap();
+    assert_eq!(info.target_program_id, TARGET_PROGRAM.into());
+    let info = games.get(&1).unwrap();
+    assert_eq!(info.target_program_id, NEW_TARGET_PROGRAM.into());
+
+    solve_game(&proxy_program, USER, 0);
+    solve_game(&proxy_program, USER, 1);
+}
+
+#[test]
+fn pause_should_block_new_games_only() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given:
+    // - A game is in progress
+    // - Admin pauses the session
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let result = proxy_program.send(USER, Action::Pause);
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::Paused));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    // When: User starts a new game
+    let result = proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // Then:
+    // - Program reverts with appropriate error message
+    // - The game in progress can still be finished
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(SESSION_PAUSED));
+    assert!(result.main_failed() && result.contains(&log));
+
+    solve_game(&proxy_program, USER, 0);
+}
+
+#[test]
+fn unpause_should_allow_new_games() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: The session is paused
+    proxy_program.send(USER, Action::Pause);
+
+    // When: Admin unpauses the session
+    let result = proxy_program.send(USER, Action::Unpause);
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::Unpaused));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    // Then: User can start a game
+    let result = proxy_program.send(USER, Action::StartGame { variant: None });
+    assert!(!result.main_failed());
+
+    let Config { paused, .. } = config_of(&proxy_program);
+    let games = games_of(&proxy_program, USER);
+    assert!(!paused);
+    let info = games.get(&0).unwrap();
+    assert_eq!(info.game_status, GameStatus::InProgress);
+}
@@ -1,13 +1,13 @@
 mod utils;
 
-use gstd::MessageId;
 use gtest::{Log, Program};
 use session::consts::{
-    err_msgs::PROGRAM_ONLY,
+    err_msgs::{GAME_NOT_PLAYABLE, PROGRAM_ONLY},
     game_rules::{DELAY_CHECK_STATUS_DURATION, MAX_ATTEMPTS},
 };
-use session_io::{Action, Event, GameOverStatus, GameStatus, State};
+use session_io::{Action, Event, EventV2, GameOverStatus, GameStatus, LetterFeedback};
 use utils::*;
+use wordle_io::{Action as WordleAction, Error as WordleError, Event as WordleEvent};
 
 #[test]
 fn check_game_status_should_fail_when_called_by_other_actor() {
@@ -20,7 +20,7 @@ fn check_game_status_should_fail_when_called_by_other_actor() {
         user_id,
         Action::CheckGameStatus {
             user: user_id.into(),
-            init_id: MessageId::zero(),
+            game_id: 0,
         },
     );
 
@@ -39,10 +39,10 @@ fn check_game_status_ignore_when_completed() {
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: maximum number of attempts is reached
-    proxy_program.send(USER, Action::StartGame);
+    proxy_program.send(USER, Action::StartGame { variant: None });
     consume_all_attempts_with_wrong_answers(&proxy_program);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(
         info.game_status,
         GameStatus::Completed(GameOverStatus::Lose)
@@ -57,33 +57,33 @@ fn check_game_status_ignore_when_completed() {
 }
 
 #[test]
-fn check_game_status_ignore_when_init_id_changed() {
+fn check_game_status_ignore_when_another_game_started() {
     let system = init_system();
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given:
     // - Maximum number of attempts is reached
-    // - User restarts the game at block `DELAY_CHECK_STATUS_DURATION` - 1
-    proxy_program.send(USER, Action::StartGame);
+    // - User starts another game at block `DELAY_CHECK_STATUS_DURATION` - 1
+    proxy_program.send(USER, Action::StartGame { variant: None });
     consume_all_attempts_with_wrong_answers(&proxy_program);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.attempts_count, MAX_ATTEMPTS);
 
-    let prev_init_id = info.init_msg_id;
-
     system.spend_blocks(DELAY_CHECK_STATUS_DURATION - 1);
-    proxy_program.send(USER, Action::StartGame);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&1).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
 
-    // When: check status period from previous session has come
+    // When: check status period from previous game has come
     let result = system.spend_blocks(1);
 
-    // Then: Result logs are empty and init ID's mismatch
+    // Then: Result logs are empty and the new game is still in progress
     assert!(result.first().unwrap().log().is_empty());
-    assert_ne!(prev_init_id, info.init_msg_id);
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&1).unwrap();
+    assert_eq!(info.game_status, GameStatus::InProgress);
 }
 
 #[test]
@@ -92,7 +92,7 @@ fn check_game_status_should_declare_game_over_when_time_is_up() {
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: A game is in progress
-    proxy_program.send(USER, Action::StartGame);
+    proxy_program.send(USER, Action::StartGame { variant: None });
 
     // When: Time is up
     let result = system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
@@ -103,15 +103,163 @@ fn check_game_status_should_declare_game_over_when_time_is_up() {
     let log = Log::builder()
         .source(PROXY_PROGRAM)
         .dest(USER)
-        .payload(Event::GameOver(GameOverStatus::Lose));
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Lose,
+        }));
     assert!(result.first().unwrap().contains(&log));
 
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    assert_eq!(
+        info.game_status,
+        GameStatus::Completed(GameOverStatus::Lose)
+    );
+}
+
+#[test]
+fn check_game_status_should_end_game_on_target_from_reservation() {
+    let system = init_system();
+    let ProgramPair {
+        target_program,
+        proxy_program,
+    } = init_programs(&system);
+
+    // Given: A game is in progress with gas reserved for its timeout
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    assert!(games.get(&0).unwrap().reservation_id.is_some());
+
+    // When: Time is up
+    system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+
+    // Then:
+    // - The reservation is used up
+    // - The target program has forgotten the game
+    let games = games_of(&proxy_program, USER);
+    assert!(games.get(&0).unwrap().reservation_id.is_none());
+
+    target_program.send(
+        USER,
+        WordleAction::AllowCaller {
+            caller: PROBE.into(),
+        },
+    );
+    let result = target_program.send(
+        PROBE,
+        WordleAction::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: ANSWER.into(),
+        },
+    );
+    let log = Log::builder()
+        .source(TARGET_PROGRAM)
+        .dest(PROBE)
+        .payload(Err::<WordleEvent, _>(WordleError::GameNotFound));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn check_game_status_should_release_reservation_when_completed_early() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // When: User wins the game before the timeout
+    solve_game(&proxy_program, USER, 0);
+
+    // Then:
+    // - The reserved gas is released
+    // - Nothing happens once the time is up
+    let games = games_of(&proxy_program, USER);
+    assert!(games.get(&0).unwrap().reservation_id.is_none());
+
+    let result = system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+    assert!(result.first().unwrap().log().is_empty());
+}
+
+#[test]
+fn check_game_status_should_track_scheduled_timeout() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: Two games are in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // When: One of them is won
+    solve_game(&proxy_program, USER, 1);
+
+    // Then:
+    // - The game in progress exposes when it times out
+    // - The won game has no pending timeout
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    assert_eq!(
+        info.times_out_at(),
+        Some(info.started_at + DELAY_CHECK_STATUS_DURATION)
+    );
+    assert_eq!(games.get(&1).unwrap().times_out_at(), None);
+
+    // And the timeout is cleared once it has fired
+    system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+    let games = games_of(&proxy_program, USER);
+    assert_eq!(games.get(&0).unwrap().times_out_at(), None);
+}
+
+#[test]
+fn check_game_status_should_stand_when_check_replied_late() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given:
+    // - A check of the user is waiting for the target program to reply
+    // - The game times out meanwhile
+    start_game_on_fake_target(&system, &proxy_program);
+    proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: ANSWER.into(),
+        },
+    );
+    system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+
+    // When: The target program replies that the word is guessed
+    let result = reply_as_fake_target(
+        &system,
+        WordleAction::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: ANSWER.into(),
+        },
+        Ok(WordleEvent::WordChecked {
+            user: USER.into(),
+            game_id: 0,
+            correct_positions: vec![0, 1, 2, 3, 4],
+            contained_in_word: vec![],
+            feedback: vec![LetterFeedback::Correct; 5],
+        }),
+    );
+
+    // Then:
+    // - The check fails with appropriate error message
+    // - The game stays lost, without the late attempt
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_PLAYABLE));
+    assert!(result.contains(&log));
+
+    let info = games_of(&proxy_program, USER).remove(&0).unwrap();
     assert_eq!(
         info.game_status,
         GameStatus::Completed(GameOverStatus::Lose)
     );
+    assert_eq!(info.attempts_count, 0);
 }
 
 fn consume_all_attempts_with_wrong_answers(program: &Program) {
@@ -119,6 +267,7 @@ fn consume_all_attempts_with_wrong_answers(program: &Program) {
         program.send(
             USER,
             Action::CheckWord {
+                game_id: 0,
                 word: WRONG_ANSWER.into(),
             },
         );
@@ -2,11 +2,12 @@ mod utils;
 
 use gtest::Log;
 use session::consts::{
-    err_msgs::{GAME_NOT_PLAYABLE, INVALID_WORD_CASE, INVALID_WORD_LEN},
+    err_msgs::{GAME_NOT_PLAYABLE, INVALID_WORD_LEN, INVALID_WORD_LETTERS},
     game_rules::MAX_ATTEMPTS,
 };
-use session_io::{Action, Event, GameOverStatus, GameStatus, State};
+use session_io::{Action, Alphabet, Event, EventV2, GameOverStatus, GameStatus, LetterFeedback};
 use utils::*;
+use wordle_io::{Action as WordleAction, Error as WordleError, Event as WordleEvent};
 
 #[test]
 fn check_word_should_work_on_wrong_answer() {
@@ -14,31 +15,32 @@ fn check_word_should_work_on_wrong_answer() {
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: A game is in progress
-    proxy_program.send(USER, Action::StartGame);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
 
     // When: User guess the word incorrectly
     let result = proxy_program.send(
         USER,
         Action::CheckWord {
+            game_id: 0,
             word: WRONG_ANSWER.into(),
         },
     );
 
     // Then:
-    //  - WordChecked event is emitted
+    //  - WordChecked event is emitted with the attempts and blocks left
     //  - User game info is updated properly
-    let log = Log::builder()
-        .source(PROXY_PROGRAM)
-        .dest(USER)
-        .payload(word_checked_on_wrong_answer_event());
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    let blocks_left = info.times_out_at().unwrap() - system.block_height();
+    let log = Log::builder().source(PROXY_PROGRAM).dest(USER).payload(
+        word_checked_on_wrong_answer_event(MAX_ATTEMPTS - 1, blocks_left),
+    );
     assert!(!result.main_failed());
     assert!(result.contains(&log));
 
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
     assert_eq!(info.attempts_count, 1);
 }
@@ -49,10 +51,10 @@ fn check_word_should_fail_when_not_playing() {
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: A game session is over
-    proxy_program.send(USER, Action::StartGame);
+    proxy_program.send(USER, Action::StartGame { variant: None });
     system.spend_blocks(200);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(
         info.game_status,
         GameStatus::Completed(GameOverStatus::Lose)
@@ -62,7 +64,8 @@ fn check_word_should_fail_when_not_playing() {
     let result = proxy_program.send(
         USER,
         Action::CheckWord {
-            word: CORRECT_ANSWER.into(),
+            game_id: 0,
+            word: WRONG_ANSWER.into(),
         },
     );
 
@@ -80,15 +83,16 @@ fn check_word_should_fail_when_invalid_length() {
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: A game is in progress
-    proxy_program.send(USER, Action::StartGame);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
 
     // When: User submits invalid length word
     let result = proxy_program.send(
         USER,
         Action::CheckWord {
+            game_id: 0,
             word: "honk".to_owned(),
         },
     );
@@ -102,48 +106,120 @@ fn check_word_should_fail_when_invalid_length() {
 }
 
 #[test]
-fn check_word_should_fail_when_not_lowercased() {
+fn check_word_should_normalize_guess() {
     let system = init_system();
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: A game is in progress
-    proxy_program.send(USER, Action::StartGame);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
-    assert_eq!(info.game_status, GameStatus::InProgress);
+    proxy_program.send(USER, Action::StartGame { variant: None });
 
-    // When: User submits five-character-long but not lowercased
+    // When: User submits the wrong answer padded and in uppercase
     let result = proxy_program.send(
         USER,
         Action::CheckWord {
-            word: "HAPPY".to_owned(),
+            game_id: 0,
+            word: format!("  {} ", WRONG_ANSWER.to_uppercase()),
         },
     );
 
-    // Then: Program reverts with invalid letter case
-    let log = Log::builder()
-        .source(PROXY_PROGRAM)
-        .dest(USER)
-        .payload_bytes(final_panic_message(INVALID_WORD_CASE));
-    assert!(result.main_failed() && result.contains(&log));
+    // Then: The guess is checked and echoed trimmed and lowercased
+    let games = games_of(&proxy_program, USER);
+    let blocks_left = games.get(&0).unwrap().times_out_at().unwrap() - system.block_height();
+    let log = Log::builder().source(PROXY_PROGRAM).dest(USER).payload(
+        word_checked_on_wrong_answer_event(MAX_ATTEMPTS - 1, blocks_left),
+    );
+    assert!(!result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn check_word_should_fail_when_letters_not_in_alphabet() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress with the latin alphabet
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    assert_eq!(info.alphabet, Alphabet::Latin);
+
+    for word in ["housé", "hous3", "ho use"] {
+        // When: User submits a word with a character that is not a latin letter
+        let result = proxy_program.send(
+            USER,
+            Action::CheckWord {
+                game_id: 0,
+                word: word.to_owned(),
+            },
+        );
+
+        // Then: Program reverts with invalid letters message
+        let log = Log::builder()
+            .source(PROXY_PROGRAM)
+            .dest(USER)
+            .payload_bytes(final_panic_message(INVALID_WORD_LETTERS));
+        assert!(result.main_failed() && result.contains(&log));
+    }
+}
+
+#[test]
+fn check_word_should_keep_best_status_of_each_letter() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User guessed the word incorrectly
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: WRONG_ANSWER.into(),
+        },
+    );
+
+    // When: User guesses the word
+    proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: ANSWER.into(),
+        },
+    );
+
+    // Then:
+    // - Letters of the answer are known to be correct
+    // - Other guessed letters are known to be absent
+    let games = games_of(&proxy_program, USER);
+    let keyboard = &games.get(&0).unwrap().keyboard;
+    for letter in ANSWER.chars().chain(WRONG_ANSWER.chars()) {
+        let expected = if ANSWER.contains(letter) {
+            LetterFeedback::Correct
+        } else {
+            LetterFeedback::Absent
+        };
+        assert_eq!(keyboard.get(&letter.to_string()), Some(&expected));
+    }
 }
 
 #[test]
 fn check_word_should_end_game_when_guessed() {
     let system = init_system();
-    let ProgramPair { proxy_program, .. } = init_programs(&system);
+    let ProgramPair {
+        target_program,
+        proxy_program,
+    } = init_programs(&system);
 
     // Given: Game is in progress
-    proxy_program.send(USER, Action::StartGame);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
 
     // When: User enters the correct word
     let result = proxy_program.send(
         USER,
         Action::CheckWord {
-            word: CORRECT_ANSWER.into(),
+            game_id: 0,
+            word: ANSWER.into(),
         },
     );
 
@@ -151,12 +227,36 @@ fn check_word_should_end_game_when_guessed() {
     let log = Log::builder()
         .source(PROXY_PROGRAM)
         .dest(USER)
-        .payload(Event::GameOver(GameOverStatus::Win));
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Win,
+        }));
     assert!(!result.main_failed() && result.contains(&log));
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.attempts_count, 1);
     assert_eq!(info.game_status, GameStatus::Completed(GameOverStatus::Win));
+
+    // And the target program has forgotten the game
+    target_program.send(
+        USER,
+        WordleAction::AllowCaller {
+            caller: PROBE.into(),
+        },
+    );
+    let result = target_program.send(
+        PROBE,
+        WordleAction::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: ANSWER.into(),
+        },
+    );
+    let log = Log::builder()
+        .source(TARGET_PROGRAM)
+        .dest(PROBE)
+        .payload(Err::<WordleEvent, _>(WordleError::GameNotFound));
+    assert!(result.contains(&log));
 }
 
 #[test]
@@ -165,11 +265,12 @@ fn check_word_should_end_game_when_all_attempts_used_up() {
     let proxy_program = init_programs(&system).proxy_program;
 
     // Given: Game is in progress and the user keeps answering wrong word
-    proxy_program.send(USER, Action::StartGame);
+    proxy_program.send(USER, Action::StartGame { variant: None });
     for _ in 0..MAX_ATTEMPTS - 1 {
         proxy_program.send(
             USER,
             Action::CheckWord {
+                game_id: 0,
                 word: WRONG_ANSWER.into(),
             },
         );
@@ -179,6 +280,7 @@ fn check_word_should_end_game_when_all_attempts_used_up() {
     let result = proxy_program.send(
         USER,
         Action::CheckWord {
+            game_id: 0,
             word: WRONG_ANSWER.into(),
         },
     );
@@ -187,14 +289,71 @@ fn check_word_should_end_game_when_all_attempts_used_up() {
     let log = Log::builder()
         .source(PROXY_PROGRAM)
         .dest(USER)
-        .payload(Event::GameOver(GameOverStatus::Lose));
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Lose,
+        }));
     assert!(result.contains(&log));
 
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.attempts_count, MAX_ATTEMPTS);
     assert_eq!(
         info.game_status,
         GameStatus::Completed(GameOverStatus::Lose)
     );
 }
+
+#[test]
+fn check_word_should_fail_while_another_check_is_pending() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A check of the user is waiting for the target program to reply
+    start_game_on_fake_target(&system, &proxy_program);
+    let check_word = |word: &str| Action::CheckWord {
+        game_id: 0,
+        word: word.into(),
+    };
+    proxy_program.send(USER, check_word(WRONG_ANSWER));
+
+    // When: User checks another word meanwhile
+    let result = proxy_program.send(USER, check_word(ANSWER));
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_PLAYABLE));
+    assert!(result.main_failed() && result.contains(&log));
+
+    // When: The target program replies to the first check
+    let result = reply_as_fake_target(
+        &system,
+        WordleAction::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: WRONG_ANSWER.into(),
+        },
+        Ok(WordleEvent::WordChecked {
+            user: USER.into(),
+            game_id: 0,
+            correct_positions: vec![1, 2, 3, 4],
+            contained_in_word: vec![],
+            feedback: vec![
+                LetterFeedback::Absent,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+            ],
+        }),
+    );
+
+    // Then: Only the first check is counted
+    assert!(!result.main_failed());
+    let info = games_of(&proxy_program, USER).remove(&0).unwrap();
+    assert_eq!(info.game_status, GameStatus::InProgress);
+    assert_eq!(info.attempts_count, 1);
+    assert_eq!(info.guesses.len(), 1);
+}
@@ -0,0 +1,115 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::{
+    cleanup::BATCH_DELAY,
+    err_msgs::{ADMIN_ONLY, INVALID_CLEANUP_LIMIT},
+};
+use session_io::{Action, Event, EventV2, State};
+use utils::*;
+
+const PLAYER: u64 = 4;
+const ANOTHER_PLAYER: u64 = 5;
+const GAMES_COUNT: u64 = 5;
+
+#[test]
+fn start_game_should_archive_finished_games() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: User plays games one after another
+    for game_id in 0..GAMES_COUNT {
+        proxy_program.send(USER, Action::StartGame { variant: None });
+        solve_game(&proxy_program, USER, game_id);
+    }
+
+    // Then:
+    // - Only the last finished game is kept
+    // - Previous games are counted in the user stats
+    let State { players, stats, .. } = full_state(&proxy_program);
+    assert_eq!(players.len(), 1);
+    assert!(players.contains_key(&(USER.into(), GAMES_COUNT - 1)));
+
+    let stats = stats.get(&USER.into()).unwrap();
+    assert_eq!(stats.games_won, GAMES_COUNT as u32 - 1);
+    assert_eq!(stats.games_lost, 0);
+}
+
+#[test]
+fn cleanup_should_fail_when_called_by_other_actor() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: A non-admin user requests a cleanup
+    let result = proxy_program.send(PLAYER, Action::Cleanup { limit: 1 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(PLAYER)
+        .payload_bytes(final_panic_message(ADMIN_ONLY));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn cleanup_should_fail_when_limit_is_zero() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has finished a game
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    solve_game(&proxy_program, USER, 0);
+
+    // When: Admin requests a cleanup of zero games at a time
+    let result = proxy_program.send(USER, Action::Cleanup { limit: 0 });
+
+    // Then:
+    // - Program reverts with appropriate error message
+    // - No batch is scheduled and the finished game is kept
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(INVALID_CLEANUP_LIMIT));
+    assert!(result.main_failed() && result.contains(&log));
+
+    system.spend_blocks(BATCH_DELAY);
+    assert_eq!(full_state(&proxy_program).players.len(), 1);
+}
+
+#[test]
+fn cleanup_should_archive_finished_games_in_batches() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given:
+    // - Both players have finished a game
+    // - Another player has a game in progress
+    for (game_id, player) in [(0, USER), (1, PLAYER)] {
+        proxy_program.send(player, Action::StartGame { variant: None });
+        solve_game(&proxy_program, player, game_id);
+    }
+    proxy_program.send(ANOTHER_PLAYER, Action::StartGame { variant: None });
+
+    // When: Admin requests a cleanup one game at a time
+    let result = proxy_program.send(USER, Action::Cleanup { limit: 1 });
+
+    // Then:
+    // - The first batch is archived right away
+    // - The next batch is archived by the program itself
+    // - The game in progress is kept
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GamesArchived { count: 1 }));
+    assert!(!result.main_failed() && result.contains(&log));
+    assert_eq!(full_state(&proxy_program).players.len(), 2);
+
+    system.spend_blocks(BATCH_DELAY);
+
+    let State { players, stats, .. } = full_state(&proxy_program);
+    assert_eq!(players.len(), 1);
+    assert!(players.contains_key(&(ANOTHER_PLAYER.into(), 2)));
+    for player in [USER, PLAYER] {
+        assert_eq!(stats.get(&player.into()).unwrap().games_won, 1);
+    }
+}
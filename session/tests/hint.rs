@@ -0,0 +1,352 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::{
+    err_msgs::{
+        ADMIN_ONLY, GAME_NOT_PLAYABLE, INSUFFICIENT_FEES, INVALID_HINT_FEE, NOTHING_TO_REVEAL,
+        NO_ATTEMPTS_FOR_HINT, TOURNAMENT_GAME_NO_HINTS, UNEXPECTED_HINT_VALUE,
+    },
+    game_rules::MAX_ATTEMPTS,
+};
+use session_io::{Action, Event, EventV2, HintPayment, TournamentConfig};
+use utils::*;
+use wordle_io::{Action as WordleAction, Event as WordleEvent};
+
+const PLAYER: u64 = 4;
+const HINT_FEE: u128 = 1_000;
+
+fn request_hint(game_id: u64, payment: HintPayment) -> Action {
+    Action::RequestHint { game_id, payment }
+}
+
+fn hint_revealed_event(position: u8, letter: &str, attempts_left: u32) -> Event {
+    Event::V2(EventV2::HintRevealed {
+        game_id: 0,
+        position,
+        letter: letter.into(),
+        attempts_left,
+    })
+}
+
+#[test]
+fn request_hint_should_reveal_letter_for_an_attempt() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // When: User pays a hint with an attempt
+    let result = proxy_program.send(USER, request_hint(0, HintPayment::Attempt));
+
+    // Then:
+    // - The first letter of the answer is revealed
+    // - The hint is recorded and has consumed an attempt
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(hint_revealed_event(0, "h", MAX_ATTEMPTS - 1));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let info = &games_of(&proxy_program, USER)[&0];
+    assert_eq!(info.hints_used, 1);
+    assert_eq!(info.attempts_count, 1);
+    assert_eq!(info.revealed.get(&0).map(String::as_str), Some("h"));
+
+    // And the hint is counted in the stats of the player once archived
+    solve_game(&proxy_program, USER, 0);
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let stats = full_state(&proxy_program).stats[&USER.into()];
+    assert_eq!(stats.hints_used, 1);
+}
+
+#[test]
+fn request_hint_should_skip_letters_found_by_guesses() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has found every letter but the first one
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: WRONG_ANSWER.into(),
+        },
+    );
+
+    // When: User asks for hints
+    let result = proxy_program.send(USER, request_hint(0, HintPayment::Attempt));
+
+    // Then:
+    // - The only unknown letter is revealed
+    // - Nothing is left to reveal afterwards
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(hint_revealed_event(0, "h", MAX_ATTEMPTS - 2));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let result = proxy_program.send(USER, request_hint(0, HintPayment::Attempt));
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(NOTHING_TO_REVEAL));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn request_hint_should_fail_when_last_attempt_left() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has one attempt left
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    for _ in 0..MAX_ATTEMPTS - 1 {
+        proxy_program.send(
+            USER,
+            Action::CheckWord {
+                game_id: 0,
+                word: WRONG_ANSWER.into(),
+            },
+        );
+    }
+
+    // When: User pays a hint with an attempt
+    let result = proxy_program.send(USER, request_hint(0, HintPayment::Attempt));
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(NO_ATTEMPTS_FOR_HINT));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn request_hint_should_fail_when_value_attached_to_attempt() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    system.mint_to(USER, HINT_FEE);
+
+    // When: User pays a hint with an attempt and attaches value
+    let result =
+        proxy_program.send_with_value(USER, request_hint(0, HintPayment::Attempt), HINT_FEE);
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(UNEXPECTED_HINT_VALUE));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn request_hint_should_fail_in_tournament_game() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User plays a tournament round
+    let registration_duration = 10;
+    proxy_program.send(
+        USER,
+        Action::CreateTournament(TournamentConfig {
+            rounds: 1,
+            registration_duration,
+            round_duration: 300,
+            prize_split: vec![100],
+            variant: None,
+        }),
+    );
+    proxy_program.send(USER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(registration_duration);
+    proxy_program.send(USER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // When: User asks for a hint
+    let result = proxy_program.send(USER, request_hint(0, HintPayment::Attempt));
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(TOURNAMENT_GAME_NO_HINTS));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn request_hint_should_be_paid_with_fee() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given:
+    // - Admin sets the hint fee
+    // - A game is in progress
+    let result = proxy_program.send(
+        USER,
+        Action::SetHintFee {
+            fee: Some(HINT_FEE),
+        },
+    );
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::HintFeeSet {
+            fee: Some(HINT_FEE),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+    assert_eq!(config_of(&proxy_program).hint_fee, Some(HINT_FEE));
+
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    system.mint_to(USER, 2 * HINT_FEE);
+
+    // When: User pays a hint with the wrong fee
+    let result = proxy_program.send_with_value(USER, request_hint(0, HintPayment::Fee), 1);
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(INVALID_HINT_FEE));
+    assert!(result.main_failed() && result.contains(&log));
+
+    // When: User pays a hint with the fee
+    let result = proxy_program.send_with_value(USER, request_hint(0, HintPayment::Fee), HINT_FEE);
+
+    // Then: The letter is revealed without consuming an attempt
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(hint_revealed_event(0, "h", MAX_ATTEMPTS));
+    assert!(!result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn withdraw_fees_should_send_collected_fees() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has paid a hint with the fee
+    proxy_program.send(
+        USER,
+        Action::SetHintFee {
+            fee: Some(HINT_FEE),
+        },
+    );
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    system.mint_to(USER, HINT_FEE);
+    proxy_program.send_with_value(USER, request_hint(0, HintPayment::Fee), HINT_FEE);
+    assert_eq!(full_state(&proxy_program).collected_fees, HINT_FEE);
+
+    // When: Admin withdraws the collected fees to the player
+    let result = proxy_program.send(
+        USER,
+        Action::WithdrawFees {
+            to: PLAYER.into(),
+            amount: HINT_FEE,
+        },
+    );
+
+    // Then:
+    // - FeesWithdrawn event is emitted to the admin and the recipient
+    // - Nothing is left to withdraw
+    let event = Event::V2(EventV2::FeesWithdrawn {
+        to: PLAYER.into(),
+        amount: HINT_FEE,
+    });
+    for dest in [USER, PLAYER] {
+        let log = Log::builder()
+            .source(PROXY_PROGRAM)
+            .dest(dest)
+            .payload(event.clone());
+        assert!(!result.main_failed() && result.contains(&log));
+    }
+    assert_eq!(full_state(&proxy_program).collected_fees, 0);
+
+    let result = proxy_program.send(
+        USER,
+        Action::WithdrawFees {
+            to: PLAYER.into(),
+            amount: 1,
+        },
+    );
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(INSUFFICIENT_FEES));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn withdraw_fees_should_fail_when_called_by_other_actor() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: A non-admin user withdraws fees
+    let result = proxy_program.send(
+        PLAYER,
+        Action::WithdrawFees {
+            to: PLAYER.into(),
+            amount: 0,
+        },
+    );
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(PLAYER)
+        .payload_bytes(final_panic_message(ADMIN_ONLY));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn request_hint_should_fail_while_another_hint_is_pending() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A hint paid with a fee is waiting for the target program to reveal a letter
+    start_game_on_fake_target(&system, &proxy_program);
+    proxy_program.send(
+        USER,
+        Action::SetHintFee {
+            fee: Some(HINT_FEE),
+        },
+    );
+    system.mint_to(USER, 2 * HINT_FEE);
+    proxy_program.send_with_value(USER, request_hint(0, HintPayment::Fee), HINT_FEE);
+
+    // When: User requests another hint meanwhile
+    let result = proxy_program.send_with_value(USER, request_hint(0, HintPayment::Fee), HINT_FEE);
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_PLAYABLE));
+    assert!(result.main_failed() && result.contains(&log));
+
+    // When: The target program reveals the letter of the first hint
+    let result = reply_as_fake_target(
+        &system,
+        WordleAction::RevealLetter {
+            user: USER.into(),
+            game_id: 0,
+            known_positions: vec![],
+        },
+        Ok(WordleEvent::LetterRevealed {
+            user: USER.into(),
+            game_id: 0,
+            position: 0,
+            letter: "h".into(),
+        }),
+    );
+
+    // Then: Only the first hint is given and paid for
+    assert!(!result.main_failed());
+    let info = games_of(&proxy_program, USER).remove(&0).unwrap();
+    assert_eq!(info.hints_used, 1);
+    assert_eq!(info.revealed.len(), 1);
+    assert_eq!(full_state(&proxy_program).collected_fees, HINT_FEE);
+}
@@ -0,0 +1,201 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::{
+    err_msgs::{GAME_NOT_PAUSED, GAME_NOT_PLAYABLE, NO_PAUSES_LEFT},
+    game_rules::{DELAY_CHECK_STATUS_DURATION, MAX_PAUSES, MAX_PAUSE_DURATION},
+};
+use session_io::{Action, Event, EventV2, GameOverStatus, GameStatus};
+use utils::*;
+
+#[test]
+fn pause_game_should_suspend_timeout() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game has been in progress for a while
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    system.spend_blocks(50);
+    let times_out_at = games_of(&proxy_program, USER)[&0].times_out_at().unwrap();
+
+    // When: User pauses the game
+    let result = proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+
+    // Then:
+    // - GamePaused event is emitted with the pauses left
+    // - The game keeps the blocks it had left and times out once the pause runs out
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GamePaused {
+            game_id: 0,
+            pauses_left: MAX_PAUSES - 1,
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let blocks_left = times_out_at - system.block_height();
+    let info = &games_of(&proxy_program, USER)[&0];
+    assert_eq!(info.game_status, GameStatus::Paused);
+    assert_eq!(info.paused_blocks_left, Some(blocks_left));
+    assert_eq!(
+        info.times_out_at(),
+        Some(system.block_height() + MAX_PAUSE_DURATION)
+    );
+
+    // And the game outlives its former deadline
+    let results = system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+    assert!(results.iter().all(|result| result.log().is_empty()));
+    assert_eq!(
+        games_of(&proxy_program, USER)[&0].game_status,
+        GameStatus::Paused
+    );
+}
+
+#[test]
+fn resume_game_should_reschedule_timeout() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is paused
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+    let blocks_left = games_of(&proxy_program, USER)[&0]
+        .paused_blocks_left
+        .unwrap();
+    system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+
+    // When: User resumes the game
+    let result = proxy_program.send(USER, Action::ResumeGame { game_id: 0 });
+
+    // Then:
+    // - GameResumed event is emitted with the new deadline
+    // - The game is in progress again
+    let deadline = system.block_height() + blocks_left;
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GameResumed {
+            game_id: 0,
+            deadline,
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let info = &games_of(&proxy_program, USER)[&0];
+    assert_eq!(info.game_status, GameStatus::InProgress);
+    assert_eq!(info.times_out_at(), Some(deadline));
+
+    // And the game times out once the blocks it had left are spent
+    let results = system.spend_blocks(blocks_left);
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Lose,
+        }));
+    assert!(results.iter().any(|result| result.contains(&log)));
+}
+
+#[test]
+fn pause_game_should_lose_game_when_not_resumed_in_time() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is paused
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+
+    // When: The pause runs out
+    let results = system.spend_blocks(MAX_PAUSE_DURATION);
+
+    // Then:
+    // - The game is lost
+    // - It can no longer be resumed
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Lose,
+        }));
+    assert!(results.iter().any(|result| result.contains(&log)));
+    assert_eq!(
+        games_of(&proxy_program, USER)[&0].game_status,
+        GameStatus::Completed(GameOverStatus::Lose)
+    );
+
+    let result = proxy_program.send(USER, Action::ResumeGame { game_id: 0 });
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_PAUSED));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn pause_game_should_fail_when_no_pauses_left() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has used up all the pauses of the game
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    for _ in 0..MAX_PAUSES {
+        proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+        proxy_program.send(USER, Action::ResumeGame { game_id: 0 });
+    }
+
+    // When: User pauses the game again
+    let result = proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(NO_PAUSES_LEFT));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn check_word_should_fail_when_game_is_paused() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is paused
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+
+    // When: User checks a word
+    let result = proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: ANSWER.into(),
+        },
+    );
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_PLAYABLE));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn resume_game_should_fail_when_not_paused() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // When: User resumes the game
+    let result = proxy_program.send(USER, Action::ResumeGame { game_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_PAUSED));
+    assert!(result.main_failed() && result.contains(&log));
+}
@@ -0,0 +1,100 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::{err_msgs::GAME_NOT_TIMED, game_rules::DELAY_CHECK_STATUS_DURATION};
+use session_io::{Action, Event, EventV2, GameMode, GameOverStatus, GameStatus};
+use utils::*;
+
+#[test]
+fn practice_game_should_not_time_out() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User starts a practice game
+    let result = proxy_program.send(USER, Action::StartPracticeGame { variant: None });
+    assert!(!result.main_failed());
+
+    let info = &games_of(&proxy_program, USER)[&0];
+    assert_eq!(info.mode, GameMode::Practice);
+    assert_eq!(info.times_out_at(), None);
+    assert!(info.reservation_id.is_none());
+
+    // When: The time of a standard game is up
+    let results = system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+
+    // Then: The game is still in progress and can be won
+    assert!(results.iter().all(|result| result.log().is_empty()));
+    assert_eq!(
+        games_of(&proxy_program, USER)[&0].game_status,
+        GameStatus::InProgress
+    );
+    solve_game(&proxy_program, USER, 0);
+}
+
+#[test]
+fn practice_game_should_end_previous_practice_game() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A practice game is in progress
+    proxy_program.send(USER, Action::StartPracticeGame { variant: None });
+
+    // When: User starts another practice game
+    let result = proxy_program.send(USER, Action::StartPracticeGame { variant: None });
+
+    // Then:
+    // - The previous practice game is lost and archived
+    // - Only the new practice game is kept
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Lose,
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let games = games_of(&proxy_program, USER);
+    assert_eq!(games.len(), 1);
+    assert_eq!(games[&1].game_status, GameStatus::InProgress);
+}
+
+#[test]
+fn practice_game_should_be_left_out_of_stats() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User wins a practice game
+    proxy_program.send(USER, Action::StartPracticeGame { variant: None });
+    solve_game(&proxy_program, USER, 0);
+
+    // When: The game is archived
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // Then: The stats of the user don't count the game
+    let stats = full_state(&proxy_program)
+        .stats
+        .get(&USER.into())
+        .copied()
+        .unwrap_or_default();
+    assert_eq!(stats.games_played(), 0);
+}
+
+#[test]
+fn practice_game_should_not_be_paused() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A practice game is in progress
+    proxy_program.send(USER, Action::StartPracticeGame { variant: None });
+
+    // When: User pauses the game
+    let result = proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_TIMED));
+    assert!(result.main_failed() && result.contains(&log));
+}
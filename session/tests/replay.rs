@@ -0,0 +1,187 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::err_msgs::{PUZZLE_IN_PLAY, TARGET_REJECTED};
+use session_io::{Action, GameMode, GameStatus, PuzzleId, TournamentConfig};
+use utils::*;
+use wordle_io::Error as WordleError;
+
+#[test]
+fn replay_puzzle_should_not_count_toward_stats() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has won a game
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    solve_game(&proxy_program, USER, 0);
+    let puzzle = games_of(&proxy_program, USER)[&0].puzzle.unwrap();
+    assert_eq!(
+        puzzle,
+        PuzzleId {
+            dictionary_version: 1,
+            index: 0,
+        }
+    );
+
+    // When: User replays the puzzle of the game
+    let result = proxy_program.send(
+        USER,
+        Action::ReplayPuzzle {
+            variant: None,
+            puzzle,
+        },
+    );
+    assert!(!result.main_failed());
+
+    // Then:
+    // - The replay is given the same puzzle
+    // - Winning the replay doesn't count toward the stats
+    let info = &games_of(&proxy_program, USER)[&1];
+    assert_eq!(info.mode, GameMode::Replay);
+    assert_eq!(info.puzzle, Some(puzzle));
+    assert!(info.times_out_at().is_some());
+
+    solve_game(&proxy_program, USER, 1);
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let stats = full_state(&proxy_program).stats[&USER.into()];
+    assert_eq!(stats.games_won, 1);
+}
+
+#[test]
+fn replay_puzzle_should_fail_when_puzzle_not_found() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: User replays a puzzle that isn't in the dictionary
+    let result = proxy_program.send(
+        USER,
+        Action::ReplayPuzzle {
+            variant: None,
+            puzzle: PuzzleId {
+                dictionary_version: 1,
+                index: 1,
+            },
+        },
+    );
+
+    // Then:
+    // - The program reverts with appropriate error message
+    // - The game is marked as rejected
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(TARGET_REJECTED));
+    assert!(result.main_failed() && result.contains(&log));
+
+    assert_eq!(
+        games_of(&proxy_program, USER)[&0].game_status,
+        GameStatus::Rejected(WordleError::PuzzleNotFound)
+    );
+}
+
+#[test]
+fn replay_puzzle_should_fail_when_game_not_over() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let puzzle = games_of(&proxy_program, USER)[&0].puzzle.unwrap();
+
+    // When: User replays the puzzle of the game
+    let result = proxy_program.send(
+        USER,
+        Action::ReplayPuzzle {
+            variant: None,
+            puzzle,
+        },
+    );
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(PUZZLE_IN_PLAY));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn replay_puzzle_should_fail_while_tournament_round_is_played() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A participant has solved the round of a tournament
+    const PLAYER: u64 = 4;
+    let (registration_duration, round_duration) = (10, 300);
+    proxy_program.send(
+        USER,
+        Action::CreateTournament(TournamentConfig {
+            rounds: 1,
+            registration_duration,
+            round_duration,
+            prize_split: vec![100],
+            variant: None,
+        }),
+    );
+    proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(registration_duration);
+    proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+    solve_game(&proxy_program, PLAYER, 0);
+    let puzzle = games_of(&proxy_program, PLAYER)[&0].puzzle.unwrap();
+    assert_eq!(tournament_of(&proxy_program, 0).puzzles[&1], puzzle);
+
+    // When: User replays the puzzle of the round
+    let replay_puzzle = Action::ReplayPuzzle {
+        variant: None,
+        puzzle,
+    };
+    let result = proxy_program.send(USER, replay_puzzle.clone());
+
+    // Then:
+    // - Program reverts with appropriate error message
+    // - The puzzle can be replayed once the round is over
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(PUZZLE_IN_PLAY));
+    assert!(result.main_failed() && result.contains(&log));
+
+    system.spend_blocks(round_duration);
+    let result = proxy_program.send(USER, replay_puzzle);
+    assert!(!result.main_failed());
+}
+
+#[test]
+fn replay_puzzle_should_work_while_puzzle_of_other_variant_is_played() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+    const VARIANT_PROGRAM: u64 = 5;
+    const VARIANT: &str = "en-hard";
+    init_target_program(&system, VARIANT_PROGRAM);
+    proxy_program.send(
+        USER,
+        Action::AddVariant {
+            name: VARIANT.into(),
+            program_id: VARIANT_PROGRAM.into(),
+        },
+    );
+
+    // Given: A game of the default variant is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let puzzle = games_of(&proxy_program, USER)[&0].puzzle.unwrap();
+
+    // When: User replays the puzzle of the same index on the other variant
+    let result = proxy_program.send(
+        USER,
+        Action::ReplayPuzzle {
+            variant: Some(VARIANT.into()),
+            puzzle,
+        },
+    );
+
+    // Then: The replay is played on the program of the variant
+    assert!(!result.main_failed());
+    let info = &games_of(&proxy_program, USER)[&1];
+    assert_eq!(info.target_program_id, VARIANT_PROGRAM.into());
+    assert_eq!(info.puzzle, Some(puzzle));
+}
@@ -0,0 +1,124 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::{
+    cleanup::KEPT_RESULTS,
+    err_msgs::{GAME_NOT_COMPLETED, GAME_NOT_FOUND},
+};
+use session_io::{Action, Event, EventV2, StateQuery, StateReply};
+use utils::*;
+
+#[test]
+fn share_result_should_reply_summary_of_completed_game() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User wins a game on the second attempt
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: WRONG_ANSWER.into(),
+        },
+    );
+    solve_game(&proxy_program, USER, 0);
+
+    // When: User shares the result of the game
+    let result = proxy_program.send(USER, Action::ShareResult { game_id: 0 });
+
+    // Then:
+    // - The summary renders the puzzle, score and grid of the game
+    // - The same summary can be read from the state
+    let summary = games_of(&proxy_program, USER)[&0].result_summary().unwrap();
+    assert_eq!(summary.text, "Wordle 1:0 2/5\n⬛🟩🟩🟩🟩\n🟩🟩🟩🟩🟩");
+
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::ResultShared {
+            game_id: 0,
+            summary: summary.clone(),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let reply: StateReply = proxy_program
+        .read_state(StateQuery::ResultSummary {
+            user: USER.into(),
+            game_id: 0,
+        })
+        .unwrap();
+    assert!(matches!(reply, StateReply::ResultSummary(Some(shared)) if shared == summary));
+}
+
+#[test]
+fn share_result_should_keep_last_results_once_archived() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has won more games than results are kept for
+    let games_count = KEPT_RESULTS as u64 + 1;
+    for game_id in 0..games_count {
+        proxy_program.send(USER, Action::StartGame { variant: None });
+        solve_game(&proxy_program, USER, game_id);
+    }
+    let summary = games_of(&proxy_program, USER)[&(games_count - 1)]
+        .result_summary()
+        .unwrap();
+
+    // When: User starts another game, which archives the finished ones
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // Then:
+    // - The last results can still be shared and read from the state
+    // - The oldest result is dropped
+    let result = proxy_program.send(
+        USER,
+        Action::ShareResult {
+            game_id: games_count - 1,
+        },
+    );
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::ResultShared {
+            game_id: games_count - 1,
+            summary: summary.clone(),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let reply: StateReply = proxy_program
+        .read_state(StateQuery::ResultSummary {
+            user: USER.into(),
+            game_id: games_count - 1,
+        })
+        .unwrap();
+    assert!(matches!(reply, StateReply::ResultSummary(Some(kept)) if kept == summary));
+
+    let result = proxy_program.send(USER, Action::ShareResult { game_id: 0 });
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_FOUND));
+    assert!(result.main_failed() && result.contains(&log));
+    assert_eq!(full_state(&proxy_program).results.len(), KEPT_RESULTS);
+}
+
+#[test]
+fn share_result_should_fail_when_game_not_completed() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is in progress
+    proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // When: User shares the result of the game
+    let result = proxy_program.send(USER, Action::ShareResult { game_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(GAME_NOT_COMPLETED));
+    assert!(result.main_failed() && result.contains(&log));
+}
@@ -1,60 +1,107 @@
-use gtest::Log;
-use session::consts::{err_msgs::GAME_IS_PLAYING, game_rules};
-use session_io::{Action, Event, GameStatus, State};
+use gstd::{prelude::Encode, ActorId};
+use gtest::{Log, Program, ProgramBuilder, RunResult, System};
+use session::consts::{
+    err_msgs::{RESERVE_GAS_FAILED, TARGET_REJECTED},
+    game_rules, gas,
+};
+use session_io::{Action, Event, EventV2, GameOverStatus, GameStatus, PuzzleId};
 use utils::*;
+use wordle_io::{Action as WordleAction, Error as WordleError};
 
 mod utils;
 
+// Program forwarding the messages of a player with the gas limit of their choice
+const SENDER: u64 = 12;
+
+fn init_sender(system: &System) -> Program<'_> {
+    let sender = ProgramBuilder::from_file(
+        "../target/wasm32-unknown-unknown/debug/session_test_sender.opt.wasm",
+    )
+    .with_id(SENDER)
+    .build(system);
+    let result = sender.send_bytes(USER, []);
+    assert!(!result.main_failed());
+    sender
+}
+
+fn start_game_with_gas(sender: &Program, gas_limit: u64) -> RunResult {
+    let payload = Action::StartGame { variant: None }.encode();
+    sender.send(USER, (ActorId::from(PROXY_PROGRAM), payload, gas_limit))
+}
+
 #[test]
 fn start_game_should_success_when_first_time() {
     let system = init_system();
     let ProgramPair { proxy_program, .. } = init_programs(&system);
 
     // Given: User has never started a game
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    assert!(!players.contains_key(&USER.into()));
+    let games = games_of(&proxy_program, USER);
+    assert!(!games.contains_key(&0));
 
     // When: User starts a game
-    let result = proxy_program.send(USER, Action::StartGame);
+    let result = proxy_program.send(USER, Action::StartGame { variant: None });
     assert!(!result.main_failed());
 
+    // Then:
+    // - User is registered in the game
+    // - User is told the puzzle, deadline and number of attempts of the game
+    // - User's info is valid
+    let games = games_of(&proxy_program, USER);
+    assert!(games.contains_key(&0));
+
+    let info = games.get(&0).unwrap();
     let log = Log::builder()
         .source(PROXY_PROGRAM)
         .dest(USER)
-        .payload(Event::GameStarted);
+        .payload(Event::V2(EventV2::GameStartedWithPuzzle {
+            game_id: 0,
+            puzzle: PuzzleId {
+                dictionary_version: 1,
+                index: 0,
+            },
+            deadline: info.started_at + game_rules::DELAY_CHECK_STATUS_DURATION,
+            max_attempts: game_rules::MAX_ATTEMPTS,
+        }));
     assert!(result.contains(&log));
 
-    // Then:
-    // - User is registered in the game
-    // - User's info is valid
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    assert!(players.contains_key(&USER.into()));
-
-    let info = players.get(&USER.into()).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
     assert_eq!(info.attempts_count, 0);
+    assert!(info.reservation_id.is_some());
 }
 
 #[test]
-fn start_game_should_fail_when_player_is_in_game() {
+fn start_game_should_work_when_player_is_in_game() {
     let system = init_system();
     let ProgramPair { proxy_program, .. } = init_programs(&system);
 
     // Given: Game is in progress
-    proxy_program.send(USER, Action::StartGame);
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(info.game_status, GameStatus::InProgress);
 
     // When: User starts another game
-    let result = proxy_program.send(USER, Action::StartGame);
+    let result = proxy_program.send(USER, Action::StartGame { variant: None });
 
-    // Then: The program reverts with appropriate error message
+    // Then:
+    // - Another game is started with a new ID
+    // - Both games are in progress
+    let games = games_of(&proxy_program, USER);
     let log = Log::builder()
         .source(PROXY_PROGRAM)
         .dest(USER)
-        .payload_bytes(final_panic_message(GAME_IS_PLAYING));
-    assert!(result.main_failed() && result.contains(&log));
+        .payload(Event::V2(EventV2::GameStartedWithPuzzle {
+            game_id: 1,
+            puzzle: games[&1].puzzle.unwrap(),
+            deadline: games.get(&1).unwrap().times_out_at().unwrap(),
+            max_attempts: game_rules::MAX_ATTEMPTS,
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    for game_id in [0, 1] {
+        let info = games.get(&game_id).unwrap();
+        assert_eq!(info.game_status, GameStatus::InProgress);
+    }
 }
 
 #[test]
@@ -63,18 +110,130 @@ fn start_game_should_work_when_available_to_play() {
     let ProgramPair { proxy_program, .. } = init_programs(&system);
 
     // Given: A game is over
-    proxy_program.send(USER, Action::StartGame);
+    proxy_program.send(USER, Action::StartGame { variant: None });
     system.spend_blocks(game_rules::DELAY_CHECK_STATUS_DURATION); // fast-forward to timeout
-    let State { players, .. } = proxy_program.read_state(0).unwrap();
-    let info = players.get(&USER.into()).unwrap();
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
     assert_eq!(
         info.game_status,
         GameStatus::Completed(session_io::GameOverStatus::Lose)
     );
 
     // When: Users start a game again
-    let result = proxy_program.send(USER, Action::StartGame);
+    let result = proxy_program.send(USER, Action::StartGame { variant: None });
 
     // Then: action should not failed
     assert!(!result.main_failed())
 }
+
+#[test]
+fn start_game_should_fail_when_target_program_rejects() {
+    let system = init_system();
+    let ProgramPair {
+        target_program,
+        proxy_program,
+    } = init_programs(&system);
+
+    // Given: Target program revokes the permission of the proxy program
+    target_program.send(
+        USER,
+        WordleAction::DisallowCaller {
+            caller: PROXY_PROGRAM.into(),
+        },
+    );
+
+    // When: User starts a game
+    let result = proxy_program.send(USER, Action::StartGame { variant: None });
+
+    // Then:
+    // - The program reverts with appropriate error message
+    // - The game is marked as rejected
+    // - The gas reserved for its timeout is released
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(TARGET_REJECTED));
+    assert!(result.main_failed() && result.contains(&log));
+
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    assert_eq!(
+        info.game_status,
+        GameStatus::Rejected(WordleError::UnauthorizedCaller)
+    );
+    assert!(info.reservation_id.is_none());
+    assert_eq!(info.times_out_at(), None);
+}
+
+#[test]
+fn start_game_should_time_out_every_game_from_its_own_reservation() {
+    let system = init_system();
+    let ProgramPair { proxy_program, .. } = init_programs(&system);
+
+    // Given: User starts several games in a row from a single account
+    for _ in 0..3 {
+        let result = proxy_program.send(USER, Action::StartGame { variant: None });
+        assert!(!result.main_failed());
+    }
+
+    // When: Time is up
+    let results = system.spend_blocks(game_rules::DELAY_CHECK_STATUS_DURATION);
+
+    // Then: Each game is declared over by its own timeout
+    for game_id in 0..3 {
+        let log = Log::builder()
+            .source(PROXY_PROGRAM)
+            .dest(USER)
+            .payload(Event::V2(EventV2::GameOver {
+                game_id,
+                status: GameOverStatus::Lose,
+            }));
+        assert!(results.iter().any(|result| result.contains(&log)));
+    }
+}
+
+#[test]
+fn start_game_should_time_out_when_started_with_little_gas() {
+    let system = init_system();
+    let ProgramPair { proxy_program, .. } = init_programs(&system);
+    let sender = init_sender(&system);
+
+    // Given: A player starts a game with barely more gas than the timeout reserves
+    let gas_limit = gas::CHECK_STATUS + gas::END_GAME + 20_000_000_000;
+    let result = start_game_with_gas(&sender, gas_limit);
+    assert!(!result.others_failed());
+    assert_eq!(
+        games_of(&proxy_program, SENDER)[&0].game_status,
+        GameStatus::InProgress
+    );
+
+    // When: Time is up
+    system.spend_blocks(game_rules::DELAY_CHECK_STATUS_DURATION);
+
+    // Then: The game is declared over by its timeout
+    assert_eq!(
+        games_of(&proxy_program, SENDER)[&0].game_status,
+        GameStatus::Completed(GameOverStatus::Lose)
+    );
+}
+
+#[test]
+fn start_game_should_fail_when_gas_cannot_be_reserved() {
+    let system = init_system();
+    let ProgramPair { proxy_program, .. } = init_programs(&system);
+    let sender = init_sender(&system);
+
+    // When: A player starts a game with less gas than the timeout reserves
+    let gas_limit = gas::CHECK_STATUS + gas::END_GAME / 2;
+    let result = start_game_with_gas(&sender, gas_limit);
+
+    // Then:
+    // - The program reverts with appropriate error message
+    // - No game is recorded
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(SENDER)
+        .payload_bytes(final_panic_message(RESERVE_GAS_FAILED));
+    assert!(result.others_failed() && result.contains(&log));
+    assert!(games_of(&proxy_program, SENDER).is_empty());
+}
@@ -0,0 +1,72 @@
+mod utils;
+
+use session_io::{Action, GameStatusKind, StateQuery, StateReply};
+use utils::*;
+
+const PLAYER: u64 = 4;
+const ANOTHER_PLAYER: u64 = 5;
+
+#[test]
+fn state_queries_should_filter_games() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given:
+    // - User and another player have a game in progress
+    // - A player has finished their game
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(PLAYER, Action::StartGame { variant: None });
+    solve_game(&proxy_program, PLAYER, 1);
+    proxy_program.send(ANOTHER_PLAYER, Action::StartGame { variant: None });
+
+    // When: Active players are read page by page
+    // Then: Only players with a game in progress are listed, in order
+    let StateReply::ActivePlayers(page) = proxy_program
+        .read_state(StateQuery::ActivePlayers {
+            offset: 0,
+            limit: 1,
+        })
+        .unwrap()
+    else {
+        panic!("Unexpected reply");
+    };
+    assert_eq!(page, vec![USER.into()]);
+
+    let StateReply::ActivePlayers(page) = proxy_program
+        .read_state(StateQuery::ActivePlayers {
+            offset: 1,
+            limit: 10,
+        })
+        .unwrap()
+    else {
+        panic!("Unexpected reply");
+    };
+    assert_eq!(page, vec![ANOTHER_PLAYER.into()]);
+
+    // When: Games are read by status
+    // Then: Only games of that status are listed
+    let StateReply::GamesByStatus(games) = proxy_program
+        .read_state(StateQuery::GamesByStatus(GameStatusKind::Completed))
+        .unwrap()
+    else {
+        panic!("Unexpected reply");
+    };
+    assert_eq!(games, vec![(PLAYER.into(), 1)]);
+
+    let StateReply::GamesByStatus(games) = proxy_program
+        .read_state(StateQuery::GamesByStatus(GameStatusKind::InProgress))
+        .unwrap()
+    else {
+        panic!("Unexpected reply");
+    };
+    assert_eq!(games, vec![(USER.into(), 0), (ANOTHER_PLAYER.into(), 2)]);
+
+    // When: Games of a player are read
+    // Then: Only that player's games are returned
+    assert_eq!(
+        games_of(&proxy_program, PLAYER)
+            .into_keys()
+            .collect::<Vec<_>>(),
+        vec![1]
+    );
+}
@@ -0,0 +1,105 @@
+mod utils;
+
+use gstd::{
+    prelude::{Decode, Encode},
+    ActorId,
+};
+use gtest::{Program, System};
+use session::consts::game_rules::DELAY_CHECK_STATUS_DURATION;
+use session_io::{Action, GameId, GameOverStatus, GameStatus, PlayerStats, StateQuery};
+use utils::*;
+
+const PLAYER: u64 = 4;
+const META_WASM: &str = "../target/wasm32-unknown-unknown/debug/session_state.meta.wasm";
+
+fn read<A: Encode, D: Decode>(program: &Program, name: &str, argument: A) -> D {
+    let meta_wasm = std::fs::read(META_WASM).expect("State functions are built");
+    program
+        .read_state_using_wasm(StateQuery::Full, name, meta_wasm, Some(argument))
+        .unwrap()
+}
+
+/// Lets the user win a game, the player lose one and start another one.
+fn play_games(system: &System, proxy_program: &Program) {
+    proxy_program.send(PLAYER, Action::StartGame { variant: None });
+    system.spend_blocks(DELAY_CHECK_STATUS_DURATION);
+
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    solve_game(proxy_program, USER, 1);
+    proxy_program.send(PLAYER, Action::StartGame { variant: None });
+}
+
+#[test]
+fn state_functions_should_read_games() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: Games have been played
+    play_games(&system, &proxy_program);
+
+    // When: Games are read through the state functions
+    // Then: Results reflect the games played
+    let status: Option<GameStatus> = read(
+        &proxy_program,
+        "player_status",
+        (ActorId::from(USER), 1 as GameId),
+    );
+    assert_eq!(status, Some(GameStatus::Completed(GameOverStatus::Win)));
+
+    let stats: PlayerStats = read(&proxy_program, "player_stats", ActorId::from(USER));
+    assert_eq!(stats.games_won, 1);
+    assert_eq!(stats.games_lost, 0);
+
+    // The lost game has been archived when the player started another one
+    let stats: PlayerStats = read(&proxy_program, "player_stats", ActorId::from(PLAYER));
+    assert_eq!(stats.games_won, 0);
+    assert_eq!(stats.games_lost, 1);
+
+    let count: u32 = read(&proxy_program, "games_in_progress_count", ());
+    assert_eq!(count, 1);
+
+    let timeout: Option<u32> = read(
+        &proxy_program,
+        "game_timeout",
+        (ActorId::from(USER), 1 as GameId),
+    );
+    assert_eq!(timeout, None);
+    let timeout: Option<u32> = read(
+        &proxy_program,
+        "game_timeout",
+        (ActorId::from(PLAYER), 2 as GameId),
+    );
+    assert!(timeout.is_some());
+}
+
+#[test]
+fn games_in_progress_count_should_include_paused_games() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A game is paused
+    proxy_program.send(USER, Action::StartGame { variant: None });
+    proxy_program.send(USER, Action::PauseGame { game_id: 0 });
+
+    // When: Games in progress are counted
+    let count: u32 = read(&proxy_program, "games_in_progress_count", ());
+
+    // Then: The paused game is counted
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn leaderboard_top_should_rank_by_games_won() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: Games have been played
+    play_games(&system, &proxy_program);
+
+    // When: The top of the leaderboard is read
+    let top: Vec<(ActorId, PlayerStats)> = read(&proxy_program, "leaderboard_top", 1u32);
+
+    // Then: The winner comes first
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].0, USER.into());
+}
@@ -0,0 +1,388 @@
+mod utils;
+
+use gtest::{Log, RunResult};
+use session::consts::{
+    err_msgs::{INVALID_PRIZE_SPLIT, NOT_A_PARTICIPANT, REGISTRATION_CLOSED, ROUND_ALREADY_PLAYED},
+    game_rules::MAX_ATTEMPTS,
+};
+use session_io::{Action, Config, Event, EventV2, GameStatus, TournamentConfig, TournamentStage};
+use utils::*;
+use wordle_io::Action as WordleAction;
+
+const PLAYER: u64 = 4;
+const REGISTRATION_DURATION: u32 = 10;
+const ROUND_DURATION: u32 = 300;
+
+fn config(rounds: u32) -> TournamentConfig {
+    TournamentConfig {
+        rounds,
+        registration_duration: REGISTRATION_DURATION,
+        round_duration: ROUND_DURATION,
+        prize_split: vec![60, 30],
+        variant: None,
+    }
+}
+
+fn any_contains(results: &[RunResult], log: &Log) -> bool {
+    results.iter().any(|result| result.contains(log))
+}
+
+#[test]
+fn create_tournament_should_work() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: User creates a tournament
+    let result = proxy_program.send(USER, Action::CreateTournament(config(2)));
+
+    // Then:
+    // - TournamentCreated event is emitted
+    // - The tournament is open for registration
+    // - Gas is reserved for every stage after the first one
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::TournamentCreated { tournament_id: 0 }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    let tournament = tournament_of(&proxy_program, 0);
+    assert_eq!(tournament.organizer, USER.into());
+    assert_eq!(tournament.stage, TournamentStage::Registration);
+    assert_eq!(tournament.config, config(2));
+    assert_eq!(tournament.reservations.len(), 2);
+}
+
+#[test]
+fn tournament_should_finish_when_nobody_registered() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User creates a tournament
+    proxy_program.send(USER, Action::CreateTournament(config(2)));
+
+    // When: Registration is over without participants
+    let results = system.spend_blocks(REGISTRATION_DURATION);
+
+    // Then:
+    // - The tournament is finished and the organizer is notified
+    // - The gas reserved for the skipped rounds is released
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::TournamentFinished {
+            tournament_id: 0,
+            place: None,
+            prize: 0,
+        }));
+    assert!(any_contains(&results, &log));
+
+    let tournament = tournament_of(&proxy_program, 0);
+    assert_eq!(tournament.stage, TournamentStage::Finished);
+    assert!(tournament.reservations.is_empty());
+}
+
+#[test]
+fn create_tournament_should_fail_when_prize_split_exceeds_pool() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: User creates a tournament awarding more than the whole pool
+    let result = proxy_program.send(
+        USER,
+        Action::CreateTournament(TournamentConfig {
+            prize_split: vec![80, 30],
+            ..config(1)
+        }),
+    );
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(INVALID_PRIZE_SPLIT));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn register_should_fail_when_registration_closed() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: Registration period is over
+    proxy_program.send(USER, Action::CreateTournament(config(1)));
+    proxy_program.send(USER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+
+    // When: Another player registers
+    let result = proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(PLAYER)
+        .payload_bytes(final_panic_message(REGISTRATION_CLOSED));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn play_round_should_fail_when_not_registered() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A round is in progress
+    proxy_program.send(USER, Action::CreateTournament(config(1)));
+    proxy_program.send(USER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+
+    // When: A player who did not register plays the round
+    let result = proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(PLAYER)
+        .payload_bytes(final_panic_message(NOT_A_PARTICIPANT));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn play_round_should_fail_when_already_played() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: User has solved the current round
+    proxy_program.send(USER, Action::CreateTournament(config(2)));
+    proxy_program.send(USER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+    proxy_program.send(USER, Action::PlayTournamentRound { tournament_id: 0 });
+    solve_game(&proxy_program, USER, 0);
+
+    // When: User plays the same round again
+    let result = proxy_program.send(USER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(ROUND_ALREADY_PLAYED));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn tournament_should_advance_rounds_and_rank_players() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: Two players are registered to a two-round tournament
+    proxy_program.send(USER, Action::CreateTournament(config(2)));
+    proxy_program.send(USER, Action::RegisterForTournament { tournament_id: 0 });
+    proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+
+    // When: Registration is over
+    let results = system.spend_blocks(REGISTRATION_DURATION);
+
+    // Then: The first round starts for every participant
+    for participant in [USER, PLAYER] {
+        let log = Log::builder()
+            .source(PROXY_PROGRAM)
+            .dest(participant)
+            .payload(Event::V2(EventV2::RoundStarted {
+                tournament_id: 0,
+                round: 1,
+            }));
+        assert!(any_contains(&results, &log));
+    }
+
+    // When:
+    // - Only the user solves the first round
+    // - Both players solve the second round
+    proxy_program.send(USER, Action::PlayTournamentRound { tournament_id: 0 });
+    solve_game(&proxy_program, USER, 0);
+    system.spend_blocks(ROUND_DURATION);
+
+    for (game_id, participant) in [(1, USER), (2, PLAYER)] {
+        proxy_program.send(
+            participant,
+            Action::PlayTournamentRound { tournament_id: 0 },
+        );
+        solve_game(&proxy_program, participant, game_id);
+    }
+    let results = system.spend_blocks(ROUND_DURATION);
+
+    // Then:
+    // - The tournament is finished
+    // - The user is ranked first and the missed round is penalized for the other player
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::TournamentFinished {
+            tournament_id: 0,
+            place: Some(1),
+            prize: 0,
+        }));
+    assert!(any_contains(&results, &log));
+
+    let tournament = tournament_of(&proxy_program, 0);
+    assert_eq!(tournament.stage, TournamentStage::Finished);
+    assert!(tournament.reservations.is_empty());
+
+    let standings = &tournament.standings;
+    assert_eq!(standings.len(), 2);
+    assert_eq!(standings[0].player, USER.into());
+    assert_eq!(standings[0].rounds_solved, 2);
+    assert_eq!(standings[1].player, PLAYER.into());
+    assert_eq!(standings[1].rounds_solved, 1);
+    assert!(standings[1].total_attempts >= MAX_ATTEMPTS);
+}
+
+#[test]
+fn tournament_should_split_prize_pool() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A one-round tournament with a prize pool and a single participant
+    let prize_pool = 10_000_000_000_000;
+    system.mint_to(USER, prize_pool);
+    proxy_program.send_with_value(USER, Action::CreateTournament(config(1)), prize_pool);
+    proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+
+    // When: The player solves the round and the tournament ends
+    proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+    solve_game(&proxy_program, PLAYER, 0);
+    let results = system.spend_blocks(ROUND_DURATION);
+
+    // Then:
+    // - The winner receives the first place share
+    // - The unawarded shares go back to the organizer
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(PLAYER)
+        .payload(Event::V2(EventV2::TournamentFinished {
+            tournament_id: 0,
+            place: Some(1),
+            prize: prize_pool * 60 / 100,
+        }));
+    assert!(any_contains(&results, &log));
+
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::TournamentFinished {
+            tournament_id: 0,
+            place: None,
+            prize: prize_pool * 40 / 100,
+        }));
+    assert!(any_contains(&results, &log));
+}
+
+#[test]
+fn tournament_should_finish_when_prize_is_dust() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A one-round tournament whose only prize is below the existential deposit
+    let prize_pool = 10_000;
+    system.mint_to(USER, prize_pool);
+    let config = TournamentConfig {
+        prize_split: vec![1],
+        ..config(1)
+    };
+    proxy_program.send_with_value(USER, Action::CreateTournament(config), prize_pool);
+    proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+
+    // When: The player solves the round and the tournament ends
+    proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+    solve_game(&proxy_program, PLAYER, 0);
+    let results = system.spend_blocks(ROUND_DURATION);
+
+    // Then:
+    // - The tournament is finished
+    // - The prize that can't be transferred goes back to the organizer
+    let tournament = tournament_of(&proxy_program, 0);
+    assert_eq!(tournament.stage, TournamentStage::Finished);
+    assert_eq!(tournament.standings[0].prize, 0);
+
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::TournamentFinished {
+            tournament_id: 0,
+            place: None,
+            prize: prize_pool,
+        }));
+    assert!(any_contains(&results, &log));
+}
+
+#[test]
+fn play_round_should_use_target_program_of_tournament() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+    let new_target_program = 20;
+    init_target_program(&system, new_target_program);
+
+    // Given: A round is in progress and the player has already played it
+    proxy_program.send(USER, Action::CreateTournament(config(1)));
+    proxy_program.send(USER, Action::RegisterForTournament { tournament_id: 0 });
+    proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+    proxy_program.send(USER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // When:
+    // - Admin replaces the target program
+    // - Another participant plays the round
+    proxy_program.send(
+        USER,
+        Action::SetTargetProgram {
+            program_id: new_target_program.into(),
+        },
+    );
+    let result = proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // Then: The round is played on the target program of the tournament
+    assert!(!result.main_failed());
+    let tournament = tournament_of(&proxy_program, 0);
+    assert_eq!(tournament.target_program_id, TARGET_PROGRAM.into());
+    assert_eq!(
+        games_of(&proxy_program, PLAYER)[&1].target_program_id,
+        TARGET_PROGRAM.into()
+    );
+}
+
+#[test]
+fn play_round_should_work_after_rejected_game() {
+    let system = init_system();
+    let ProgramPair {
+        target_program,
+        proxy_program,
+    } = init_programs(&system);
+
+    // Given: The game of the player for the round is rejected by the target program
+    proxy_program.send(USER, Action::CreateTournament(config(1)));
+    proxy_program.send(PLAYER, Action::RegisterForTournament { tournament_id: 0 });
+    system.spend_blocks(REGISTRATION_DURATION);
+    target_program.send(
+        USER,
+        WordleAction::DisallowCaller {
+            caller: PROXY_PROGRAM.into(),
+        },
+    );
+    proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // When: The player plays the round again once the target program accepts games
+    target_program.send(
+        USER,
+        WordleAction::AllowCaller {
+            caller: PROXY_PROGRAM.into(),
+        },
+    );
+    let result = proxy_program.send(PLAYER, Action::PlayTournamentRound { tournament_id: 0 });
+
+    // Then: The round is played in a new game
+    assert!(!result.main_failed());
+    assert_eq!(
+        games_of(&proxy_program, PLAYER)[&1].game_status,
+        GameStatus::InProgress
+    );
+}
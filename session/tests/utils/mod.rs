@@ -1,17 +1,33 @@
 use gstd::ActorId;
-use gtest::{Program, ProgramBuilder, System};
-use session_io::Event;
+use gtest::{Log, Program, ProgramBuilder, RunResult, System};
+use session_io::{
+    Action, Config, Event, EventV2, GameId, GameOverStatus, LetterFeedback, PlayerInfo, PuzzleId,
+    State, StateQuery, StateReply, Tournament, TournamentId,
+};
+use std::collections::BTreeMap;
+use wordle_io::{
+    encode_word, Action as WordleAction, Alphabet, Error as WordleError, Event as WordleEvent,
+    InitConfig,
+};
 
 pub const PROXY_PROGRAM: u64 = 1;
 pub const TARGET_PROGRAM: u64 = 2;
 
 pub const USER: u64 = 3;
 
+// Actor that can be allowed to call the target program directly
 #[allow(unused)]
-pub const WRONG_ANSWER: &str = "human"; // item at index 0 or 1 in `BANK_OF_WORDS` is the wrong answer
+pub const PROBE: u64 = 10;
 
+// Actor standing in for the target program, so that tests decide when and how it replies
 #[allow(unused)]
-pub const CORRECT_ANSWER: &str = "horse"; // item at index 2 in `BANK_OF_WORDS` is the correct answer
+pub const FAKE_TARGET: u64 = 11;
+
+// The only word of the dictionary target programs are initialized with
+pub const ANSWER: &str = "house";
+
+#[allow(unused)]
+pub const WRONG_ANSWER: &str = "mouse";
 
 pub struct ProgramPair<'a> {
     #[allow(dead_code)]
@@ -25,16 +41,37 @@ pub fn init_system() -> System {
     system
 }
 
-pub fn init_programs(sys: &System) -> ProgramPair<'_> {
-    let proxy_program = Program::current(sys);
+pub fn init_target_program(sys: &System, id: u64) -> Program<'_> {
     let target_program =
         ProgramBuilder::from_file("../target/wasm32-unknown-unknown/debug/wordle.opt.wasm")
-            .with_id(TARGET_PROGRAM)
+            .with_id(id)
             .build(sys);
 
-    let result = target_program.send_bytes(USER, []);
+    let result = target_program.send(
+        USER,
+        InitConfig {
+            allowed_callers: vec![PROXY_PROGRAM.into()],
+        },
+    );
     assert!(!result.main_failed());
 
+    target_program.send(
+        USER,
+        WordleAction::UploadWords {
+            alphabet: Alphabet::Latin,
+            words: vec![encode_word(ANSWER).unwrap()],
+        },
+    );
+    let result = target_program.send(USER, WordleAction::PublishDictionary);
+    assert!(!result.main_failed());
+
+    target_program
+}
+
+pub fn init_programs(sys: &System) -> ProgramPair<'_> {
+    let proxy_program = Program::current(sys);
+    let target_program = init_target_program(sys, TARGET_PROGRAM);
+
     let result = proxy_program.send::<_, ActorId>(USER, TARGET_PROGRAM.into());
     assert!(!result.main_failed());
 
@@ -44,21 +81,125 @@ pub fn init_programs(sys: &System) -> ProgramPair<'_> {
     }
 }
 
+/// Points the proxy program to `FAKE_TARGET` and starts game 0 of `USER` there.
+#[allow(unused)]
+pub fn start_game_on_fake_target(system: &System, program: &Program) {
+    program.send(
+        USER,
+        Action::SetTargetProgram {
+            program_id: FAKE_TARGET.into(),
+        },
+    );
+    program.send(USER, Action::StartGame { variant: None });
+
+    let result = reply_as_fake_target(
+        system,
+        WordleAction::StartGame {
+            user: USER.into(),
+            game_id: 0,
+            word_key: None,
+            puzzle: None,
+        },
+        Ok(WordleEvent::GameStarted {
+            user: USER.into(),
+            game_id: 0,
+            alphabet: Alphabet::Latin,
+            puzzle: PuzzleId::default(),
+        }),
+    );
+    assert!(!result.main_failed());
+}
+
+/// Replies to the `action` the proxy program sent to `FAKE_TARGET`.
+#[allow(unused)]
+pub fn reply_as_fake_target(
+    system: &System,
+    action: WordleAction,
+    reply: Result<WordleEvent, WordleError>,
+) -> RunResult {
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(FAKE_TARGET)
+        .payload(action);
+    let mailbox = system.get_mailbox(FAKE_TARGET);
+    assert!(mailbox.contains(&log));
+    mailbox.take_message(log).reply(reply, 0)
+}
+
+/// Wins the given game of `user` by guessing the answer.
+#[allow(unused)]
+pub fn solve_game(program: &Program, user: u64, game_id: GameId) {
+    let result = program.send(
+        user,
+        Action::CheckWord {
+            game_id,
+            word: ANSWER.into(),
+        },
+    );
+
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(user)
+        .payload(Event::V2(EventV2::GameOver {
+            game_id,
+            status: GameOverStatus::Win,
+        }));
+    assert!(result.contains(&log));
+}
+
+/// Reads the games of `user`, keyed by game id.
+#[allow(unused)]
+pub fn games_of(program: &Program, user: u64) -> BTreeMap<GameId, PlayerInfo> {
+    match program.read_state(StateQuery::Player(user.into())).unwrap() {
+        StateReply::Player(games) => games.into_iter().collect(),
+        reply => panic!("Unexpected reply: {reply:?}"),
+    }
+}
+
+#[allow(unused)]
+pub fn full_state(program: &Program) -> State {
+    match program.read_state(StateQuery::Full).unwrap() {
+        StateReply::Full(state) => state,
+        reply => panic!("Unexpected reply: {reply:?}"),
+    }
+}
+
+#[allow(unused)]
+pub fn config_of(program: &Program) -> Config {
+    match program.read_state(StateQuery::Config).unwrap() {
+        StateReply::Config(config) => config,
+        reply => panic!("Unexpected reply: {reply:?}"),
+    }
+}
+
+#[allow(unused)]
+pub fn tournament_of(program: &Program, tournament_id: TournamentId) -> Tournament {
+    match program
+        .read_state(StateQuery::Tournament(tournament_id))
+        .unwrap()
+    {
+        StateReply::Tournament(Some(tournament)) => tournament,
+        reply => panic!("Unexpected reply: {reply:?}"),
+    }
+}
+
 // https://docs.rs/gstd/latest/gstd/#panic-handler-profiles
+#[allow(unused)]
 pub fn final_panic_message(message: &str) -> String {
     println!("Reverts with: {message}");
     "Panic occurred: panicked with '<unknown>'".into()
 }
 
 #[allow(unused)]
-pub fn word_checked_on_wrong_answer_event() -> Event {
+pub fn word_checked_on_wrong_answer_event(attempts_left: u32, blocks_left: u32) -> Event {
+    let answer = ANSWER;
     use gstd::collections::HashSet;
 
     let mut correct_positions: Vec<u8> = Vec::new();
     let mut contained_in_word: Vec<u8> = Vec::new();
     let mut used_indices = HashSet::new();
 
-    for (i, (s_char, u_char)) in CORRECT_ANSWER.chars().zip(WRONG_ANSWER.chars()).enumerate() {
+    for (i, (s_char, u_char)) in answer.chars().zip(WRONG_ANSWER.chars()).enumerate() {
         if s_char == u_char {
             correct_positions.push(i.try_into().unwrap());
             used_indices.insert(i);
@@ -66,9 +207,9 @@ pub fn word_checked_on_wrong_answer_event() -> Event {
     }
 
     for (i, u_char) in WRONG_ANSWER.chars().enumerate() {
-        if !used_indices.contains(&i) && CORRECT_ANSWER.contains(u_char) {
+        if !used_indices.contains(&i) && answer.contains(u_char) {
             let mut added = false;
-            for (j, s_char) in CORRECT_ANSWER.chars().enumerate() {
+            for (j, s_char) in answer.chars().enumerate() {
                 if s_char == u_char && !used_indices.contains(&j) {
                     contained_in_word.push(i.try_into().unwrap());
                     used_indices.insert(j);
@@ -83,8 +224,24 @@ pub fn word_checked_on_wrong_answer_event() -> Event {
         }
     }
 
-    Event::WordChecked {
+    let feedback = (0..WRONG_ANSWER.len() as u8)
+        .map(|i| {
+            if correct_positions.contains(&i) {
+                LetterFeedback::Correct
+            } else if contained_in_word.contains(&i) {
+                LetterFeedback::Present
+            } else {
+                LetterFeedback::Absent
+            }
+        })
+        .collect();
+
+    Event::V2(EventV2::WordChecked {
+        word: WRONG_ANSWER.into(),
         correct_positions,
         contained_in_word,
-    }
+        feedback,
+        attempts_left,
+        blocks_left,
+    })
 }
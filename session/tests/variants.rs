@@ -0,0 +1,153 @@
+mod utils;
+
+use gtest::Log;
+use session::consts::err_msgs::{ADMIN_ONLY, VARIANT_NOT_FOUND};
+use session_io::{Action, Alphabet, Config, Event, EventV2, GameOverStatus, GameStatus};
+use utils::*;
+use wordle_io::Action as WordleAction;
+
+const VARIANT_PROGRAM: u64 = 5;
+const VARIANT: &str = "en-hard";
+
+fn add_variant_action() -> Action {
+    Action::AddVariant {
+        name: VARIANT.into(),
+        program_id: VARIANT_PROGRAM.into(),
+    }
+}
+
+#[test]
+fn add_variant_should_fail_when_called_by_other_actor() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // When: A non-admin user registers a variant
+    let user_id = 999u64;
+    let result = proxy_program.send(user_id, add_variant_action());
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(user_id)
+        .payload_bytes(final_panic_message(ADMIN_ONLY));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn start_game_should_use_target_program_of_variant() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+    init_target_program(&system, VARIANT_PROGRAM);
+
+    // Given: Admin registers a variant
+    let result = proxy_program.send(USER, add_variant_action());
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::VariantAdded {
+            name: VARIANT.into(),
+            program_id: VARIANT_PROGRAM.into(),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+
+    // When: User starts a game on the variant
+    let result = proxy_program.send(
+        USER,
+        Action::StartGame {
+            variant: Some(VARIANT.into()),
+        },
+    );
+
+    // Then:
+    // - The game is started on the variant's target program
+    // - The game can be played to the end
+    assert!(!result.main_failed());
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    assert_eq!(info.target_program_id, VARIANT_PROGRAM.into());
+    assert_eq!(info.game_status, GameStatus::InProgress);
+
+    solve_game(&proxy_program, USER, 0);
+}
+
+#[test]
+fn start_game_should_fail_when_variant_removed() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+
+    // Given: A variant has been registered then removed
+    proxy_program.send(USER, add_variant_action());
+    let result = proxy_program.send(
+        USER,
+        Action::RemoveVariant {
+            name: VARIANT.into(),
+        },
+    );
+    assert!(!result.main_failed());
+    let Config { variants, .. } = config_of(&proxy_program);
+    assert!(variants.is_empty());
+
+    // When: User starts a game on the variant
+    let result = proxy_program.send(
+        USER,
+        Action::StartGame {
+            variant: Some(VARIANT.into()),
+        },
+    );
+
+    // Then: Program reverts with appropriate error message
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload_bytes(final_panic_message(VARIANT_NOT_FOUND));
+    assert!(result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn variant_should_be_played_in_its_alphabet() {
+    let system = init_system();
+    let proxy_program = init_programs(&system).proxy_program;
+    let variant_program = init_target_program(&system, VARIANT_PROGRAM);
+
+    // Given: The variant program plays with a cyrillic dictionary
+    variant_program.send(
+        USER,
+        WordleAction::UploadWords {
+            alphabet: Alphabet::Cyrillic,
+            words: vec![Alphabet::Cyrillic.encode("книга").unwrap()],
+        },
+    );
+    variant_program.send(USER, WordleAction::PublishDictionary);
+    proxy_program.send(USER, add_variant_action());
+
+    // When: User starts a game on the variant and guesses the word
+    proxy_program.send(
+        USER,
+        Action::StartGame {
+            variant: Some(VARIANT.into()),
+        },
+    );
+    let result = proxy_program.send(
+        USER,
+        Action::CheckWord {
+            game_id: 0,
+            word: "книга".into(),
+        },
+    );
+
+    // Then:
+    // - The game is played in the cyrillic alphabet
+    // - The game is won
+    let games = games_of(&proxy_program, USER);
+    let info = games.get(&0).unwrap();
+    assert_eq!(info.alphabet, Alphabet::Cyrillic);
+
+    let log = Log::builder()
+        .source(PROXY_PROGRAM)
+        .dest(USER)
+        .payload(Event::V2(EventV2::GameOver {
+            game_id: 0,
+            status: GameOverStatus::Win,
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+}
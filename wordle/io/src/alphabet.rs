@@ -0,0 +1,95 @@
+use crate::{WordCode, WORD_LENGTH};
+use gstd::prelude::*;
+
+const LATIN: &str = "abcdefghijklmnopqrstuvwxyz";
+const LATIN_WITH_DIACRITICS: &str = "abcdefghijklmnopqrstuvwxyzßàáâãäåæçèéêëìíîïñòóôõöøœùúûüýÿ";
+const CYRILLIC: &str = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя";
+
+/// Lowercase letters the words of a dictionary are made of.
+///
+/// Words are measured in characters, not bytes, and are packed into a [`WordCode`]
+/// using the index of each letter in the alphabet.
+#[derive(Debug, Default, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, Hash)]
+pub enum Alphabet {
+    /// `a` to `z`, e.g. English or Indonesian.
+    #[default]
+    #[codec(index = 0)]
+    Latin,
+    /// `a` to `z` and accented letters, e.g. German, French or Spanish.
+    #[codec(index = 1)]
+    LatinWithDiacritics,
+    /// Russian letters, `ё` included.
+    #[codec(index = 2)]
+    Cyrillic,
+}
+
+impl Alphabet {
+    pub fn letters(&self) -> &'static str {
+        match self {
+            Alphabet::Latin => LATIN,
+            Alphabet::LatinWithDiacritics => LATIN_WITH_DIACRITICS,
+            Alphabet::Cyrillic => CYRILLIC,
+        }
+    }
+
+    /// Number of bits needed to store the index of a letter.
+    pub fn bits_per_letter(&self) -> usize {
+        let len = self.letters().chars().count();
+        (usize::BITS - (len - 1).leading_zeros()) as usize
+    }
+
+    pub fn contains(&self, letter: char) -> bool {
+        self.letters().contains(letter)
+    }
+
+    /// Returns the lowercase form of `letter`, `None` if it's not a letter of the alphabet.
+    pub fn to_lowercase(&self, letter: char) -> Option<char> {
+        let mut lowercase = letter.to_lowercase();
+        match (lowercase.next(), lowercase.next()) {
+            (Some(letter), None) if self.contains(letter) => Some(letter),
+            _ => None,
+        }
+    }
+
+    /// Trims and lowercases `word`, `None` if it contains anything but letters of the alphabet.
+    pub fn normalize(&self, word: &str) -> Option<String> {
+        word.trim()
+            .chars()
+            .map(|letter| self.to_lowercase(letter))
+            .collect()
+    }
+
+    /// Whether `word` is made of `WORD_LENGTH` letters of the alphabet.
+    pub fn is_word(&self, word: &str) -> bool {
+        word.chars().count() == WORD_LENGTH && word.chars().all(|letter| self.contains(letter))
+    }
+
+    /// Packs a word of `WORD_LENGTH` letters of the alphabet, the first letter in the lowest bits.
+    pub fn encode(&self, word: &str) -> Option<WordCode> {
+        if !self.is_word(word) {
+            return None;
+        }
+
+        let bits = self.bits_per_letter();
+        word.chars().enumerate().try_fold(0, |code, (i, letter)| {
+            let index = self.letters().chars().position(|l| l == letter)?;
+            Some(code | (index as WordCode) << (i * bits))
+        })
+    }
+
+    /// Unpacks a word packed with [`Alphabet::encode`], `None` if the code is not a valid word.
+    pub fn decode(&self, code: WordCode) -> Option<String> {
+        let bits = self.bits_per_letter();
+        if u64::from(code) >> (bits * WORD_LENGTH) != 0 {
+            return None;
+        }
+
+        let mask = (1 << bits) - 1;
+        (0..WORD_LENGTH)
+            .map(|i| {
+                let index = (code >> (i * bits)) & mask;
+                self.letters().chars().nth(index as usize)
+            })
+            .collect()
+    }
+}
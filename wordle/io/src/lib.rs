@@ -1,42 +1,209 @@
 #![no_std]
-use gmeta::{InOut, Metadata};
+use gmeta::{In, InOut, Metadata};
 use gstd::{prelude::*, ActorId};
 
+mod alphabet;
+pub use alphabet::Alphabet;
+
 pub struct WordleMetadata;
 impl Metadata for WordleMetadata {
-    type Init = ();
-    type Handle = InOut<Action, Event>;
+    type Init = In<InitConfig>;
+    type Handle = InOut<Action, Result<Event, Error>>;
     type Others = ();
     type Reply = ();
     type Signal = ();
     type State = ();
 }
 
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
+pub struct InitConfig {
+    /// Programs allowed to start and check games, e.g. the session program.
+    pub allowed_callers: Vec<ActorId>,
+}
+
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 pub enum Action {
-    StartGame { user: ActorId },
-    CheckWord { user: ActorId, word: String },
+    /// Starts a game for `user`.
+    ///
+    /// Games started by the same caller with the same `word_key` are given the same word,
+    /// which lets a caller run several games (e.g. a tournament round) on one puzzle.
+    /// A game started with `puzzle` replays that puzzle of the current dictionary instead.
+    StartGame {
+        user: ActorId,
+        game_id: GameId,
+        word_key: Option<u64>,
+        puzzle: Option<PuzzleId>,
+    },
+    CheckWord {
+        user: ActorId,
+        game_id: GameId,
+        word: String,
+    },
+    /// Ends the game with the given outcome, after which its word is forgotten
+    /// and further checks are rejected.
+    EndGame {
+        user: ActorId,
+        game_id: GameId,
+        outcome: GameOutcome,
+    },
+    /// Allows `caller` to start and check games. Callable by owner only.
+    AllowCaller { caller: ActorId },
+    /// Revokes the permission of `caller` to start and check games. Callable by owner only.
+    DisallowCaller { caller: ActorId },
+    /// Appends a chunk of words encoded with [`Alphabet::encode`] to the pending dictionary.
+    /// All chunks of a dictionary must use the same alphabet. Callable by owner only.
+    UploadWords {
+        alphabet: Alphabet,
+        words: Vec<WordCode>,
+    },
+    /// Replaces the dictionary new games are given words from with the uploaded words.
+    /// Callable by owner only.
+    PublishDictionary,
+    /// Reveals the letter at one of the positions of the word that are not in `known_positions`.
+    RevealLetter {
+        user: ActorId,
+        game_id: GameId,
+        known_positions: Vec<u8>,
+    },
+    /// Forgets the word shared by the games the caller started with `word_key`,
+    /// e.g. once the round they were played for is over. Games already started keep their word.
+    ForgetWord { word_key: u64 },
 }
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 pub enum Event {
     GameStarted {
         user: ActorId,
+        game_id: GameId,
+        /// Alphabet the word of the game is made of.
+        alphabet: Alphabet,
+        /// The puzzle the game is given, which can be replayed with it.
+        puzzle: PuzzleId,
     },
     WordChecked {
         user: ActorId,
+        game_id: GameId,
         correct_positions: Vec<u8>,
         contained_in_word: Vec<u8>,
+        /// Feedback for the letter at each position of the guess.
+        feedback: Vec<LetterFeedback>,
+    },
+    GameEnded {
+        user: ActorId,
+        game_id: GameId,
+        outcome: GameOutcome,
+    },
+    CallerAllowed {
+        caller: ActorId,
+    },
+    CallerDisallowed {
+        caller: ActorId,
+    },
+    WordsUploaded {
+        /// Number of words uploaded since the last published dictionary.
+        pending: u32,
+    },
+    DictionaryPublished {
+        version: u32,
+        words: u32,
     },
+    LetterRevealed {
+        user: ActorId,
+        game_id: GameId,
+        position: u8,
+        letter: String,
+    },
+    WordForgotten {
+        word_key: u64,
+    },
+}
+
+/// How a letter of a guess matches the word.
+///
+/// Variants are ordered from the least to the most informative, so the best known
+/// status of a letter across several guesses is the greatest one.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LetterFeedback {
+    /// The letter is not in the word.
+    #[codec(index = 0)]
+    Absent,
+    /// The letter is in the word at another position.
+    #[codec(index = 1)]
+    Present,
+    /// The letter is in the word at this position.
+    #[codec(index = 2)]
+    Correct,
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum Error {
+    /// The caller is not allowed to start and check games.
+    UnauthorizedCaller,
+    /// The action is reserved to the owner of the program.
+    NotOwner,
+    /// An uploaded code or a checked word is not a word of the alphabet in use.
+    InvalidWord,
+    /// The uploaded chunk uses another alphabet than the previous ones.
+    AlphabetMismatch,
+    /// No words have been uploaded since the last published dictionary.
+    EmptyDictionary,
+    /// The game doesn't exist or has already ended.
+    GameNotFound,
+    /// The word of the game has already been guessed.
+    AlreadySolved,
+    /// All `MAX_ATTEMPTS` checks of the game have been made.
+    NoAttemptsLeft,
+    /// Every position of the word is already known.
+    NothingToReveal,
+    /// The puzzle is not part of the current dictionary.
+    PuzzleNotFound,
+}
+
+/// How a game ended, as decided by the caller.
+#[derive(Debug, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq)]
+pub enum GameOutcome {
+    Win,
+    Lose,
 }
 
 pub const WORD_LENGTH: usize = 5;
+/// Number of words that can be checked in a game.
+pub const MAX_ATTEMPTS: u32 = 5;
+
+/// A word packed with the index of each letter in its [`Alphabet`].
+pub type WordCode = u32;
+
+/// Packs a word of `WORD_LENGTH` lowercase latin letters, `None` if the word is not one.
+pub fn encode_word(word: &str) -> Option<WordCode> {
+    Alphabet::Latin.encode(word)
+}
+
+/// Unpacks a word packed with [`encode_word`], `None` if the code is not a valid word.
+pub fn decode_word(code: WordCode) -> Option<String> {
+    Alphabet::Latin.decode(code)
+}
+
+/// Identifies one of the games of a user.
+pub type GameId = u64;
+
+/// Identifies a word of a dictionary, stable for as long as the dictionary is in use.
+#[derive(Debug, Default, Clone, Copy, Encode, Decode, TypeInfo, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PuzzleId {
+    /// Version of the dictionary, as reported by `DictionaryPublished`.
+    pub dictionary_version: u32,
+    /// Index of the word in the dictionary.
+    pub index: u32,
+}
 
-impl From<Event> for ActorId {
-    fn from(event: Event) -> Self {
-        match event {
-            Event::GameStarted { user } => user,
-            Event::WordChecked { user, .. } => user,
+impl Event {
+    /// Returns the user and the ID of the game the event relates to, if any.
+    pub fn game(&self) -> Option<(ActorId, GameId)> {
+        match self {
+            Event::GameStarted { user, game_id, .. } => Some((*user, *game_id)),
+            Event::WordChecked { user, game_id, .. } => Some((*user, *game_id)),
+            Event::GameEnded { user, game_id, .. } => Some((*user, *game_id)),
+            Event::LetterRevealed { user, game_id, .. } => Some((*user, *game_id)),
+            _ => None,
         }
     }
 }
@@ -1,21 +1,219 @@
 #![no_std]
-use gstd::{collections::HashMap, exec, msg, prelude::*, ActorId};
+use gstd::{
+    collections::{HashMap, HashSet},
+    msg,
+    prelude::*,
+    ActorId,
+};
+use random::Random;
+use seen_words::SeenWords;
 use wordle_io::*;
 
+mod random;
+mod seen_words;
+
 static mut WORDLE: Option<Wordle> = None;
 
+// Dictionary the program starts with until the owner publishes another one
 const BANK_OF_WORDS: [&str; 3] = ["house", "human", "horse"];
 
+struct Game {
+    word: WordCode,
+    alphabet: Alphabet,
+    attempts: u32,
+    solved: bool,
+}
+
 #[derive(Default)]
 struct Wordle {
-    games: HashMap<ActorId, String>,
+    owner: ActorId,
+    allowed_callers: HashSet<ActorId>,
+    /// Games keyed by the caller that started them, as each caller numbers its own games.
+    games: HashMap<(ActorId, ActorId, GameId), Game>,
+    /// Words shared by key, along with the alphabet of the dictionary they were picked from.
+    shared_words: HashMap<(ActorId, u64), (PuzzleId, Alphabet, WordCode)>,
+    random: Random,
+    seen_words: HashMap<ActorId, SeenWords>,
+    dictionary: Vec<WordCode>,
+    dictionary_alphabet: Alphabet,
+    dictionary_version: u32,
+    pending_words: Vec<WordCode>,
+    pending_alphabet: Alphabet,
+}
+
+impl Wordle {
+    /// Picks a random word among the ones `user` hasn't been given yet,
+    /// starting over once every word of the dictionary has been given.
+    fn pick_unseen_word(&mut self, user: ActorId) -> (PuzzleId, WordCode) {
+        let dictionary_len = self.dictionary.len() as u32;
+        let seen = self.seen_words.entry(user).or_default();
+        if seen.len() >= dictionary_len {
+            seen.clear();
+        }
+
+        let n = self.random.next_u32(user, dictionary_len - seen.len());
+        let index = seen.nth_unseen(n);
+        (self.puzzle(index), self.dictionary[index as usize])
+    }
+
+    fn puzzle(&self, index: u32) -> PuzzleId {
+        PuzzleId {
+            dictionary_version: self.dictionary_version,
+            index,
+        }
+    }
+
+    /// Returns the word of a puzzle of the current dictionary.
+    fn word_of(&self, puzzle: PuzzleId) -> Result<WordCode, Error> {
+        if puzzle.dictionary_version != self.dictionary_version {
+            return Err(Error::PuzzleNotFound);
+        }
+        self.dictionary
+            .get(puzzle.index as usize)
+            .copied()
+            .ok_or(Error::PuzzleNotFound)
+    }
+
+    fn start_game(
+        &mut self,
+        user: ActorId,
+        game_id: GameId,
+        word_key: Option<u64>,
+        puzzle: Option<PuzzleId>,
+    ) -> Result<Event, Error> {
+        let shared_key = word_key.map(|key| (msg::source(), key));
+        let shared_word = shared_key.and_then(|key| self.shared_words.get(&key));
+        // A shared word keeps the alphabet it was encoded with, even once the dictionary is replaced
+        let (puzzle, alphabet, code) = match (puzzle, shared_word) {
+            (Some(puzzle), _) => (puzzle, self.dictionary_alphabet, self.word_of(puzzle)?),
+            (None, Some(&shared_word)) => shared_word,
+            (None, None) => {
+                let (puzzle, code) = self.pick_unseen_word(user);
+                (puzzle, self.dictionary_alphabet, code)
+            }
+        };
+        if let Some(key) = shared_key {
+            self.shared_words.insert(key, (puzzle, alphabet, code));
+        }
+        // Words shared before the dictionary was replaced are not part of the current one
+        if puzzle.dictionary_version == self.dictionary_version {
+            self.seen_words
+                .entry(user)
+                .or_default()
+                .insert(puzzle.index);
+        }
+        self.games.insert(
+            (msg::source(), user, game_id),
+            Game {
+                word: code,
+                alphabet,
+                attempts: 0,
+                solved: false,
+            },
+        );
+        Ok(Event::GameStarted {
+            user,
+            game_id,
+            alphabet,
+            puzzle,
+        })
+    }
+
+    fn check_word(&mut self, user: ActorId, game_id: GameId, word: String) -> Result<Event, Error> {
+        let game = self
+            .games
+            .get_mut(&(msg::source(), user, game_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.solved {
+            return Err(Error::AlreadySolved);
+        }
+        if game.attempts >= MAX_ATTEMPTS {
+            return Err(Error::NoAttemptsLeft);
+        }
+        if !game.alphabet.is_word(&word) {
+            return Err(Error::InvalidWord);
+        }
+        let key_word = game
+            .alphabet
+            .decode(game.word)
+            .expect("Stored words are valid");
+        let mut matched_indices = Vec::with_capacity(WORD_LENGTH);
+        let mut key_indices = Vec::with_capacity(WORD_LENGTH);
+        let mut feedback = Vec::with_capacity(WORD_LENGTH);
+        for (i, (a, b)) in key_word.chars().zip(word.chars()).enumerate() {
+            if a == b {
+                matched_indices.push(i as u8);
+                feedback.push(LetterFeedback::Correct);
+            } else if key_word.contains(b) {
+                key_indices.push(i as u8);
+                feedback.push(LetterFeedback::Present);
+            } else {
+                feedback.push(LetterFeedback::Absent);
+            }
+        }
+        game.attempts += 1;
+        game.solved = matched_indices.len() == WORD_LENGTH;
+
+        Ok(Event::WordChecked {
+            user,
+            game_id,
+            correct_positions: matched_indices,
+            contained_in_word: key_indices,
+            feedback,
+        })
+    }
+
+    fn reveal_letter(
+        &self,
+        user: ActorId,
+        game_id: GameId,
+        known_positions: Vec<u8>,
+    ) -> Result<Event, Error> {
+        let game = self
+            .games
+            .get(&(msg::source(), user, game_id))
+            .ok_or(Error::GameNotFound)?;
+        if game.solved {
+            return Err(Error::AlreadySolved);
+        }
+        let key_word = game
+            .alphabet
+            .decode(game.word)
+            .expect("Stored words are valid");
+        let (position, letter) = key_word
+            .chars()
+            .enumerate()
+            .find(|(i, _)| !known_positions.contains(&(*i as u8)))
+            .ok_or(Error::NothingToReveal)?;
+
+        Ok(Event::LetterRevealed {
+            user,
+            game_id,
+            position: position as u8,
+            letter: letter.to_string(),
+        })
+    }
 }
 
 #[no_mangle]
 extern "C" fn init() {
+    let InitConfig { allowed_callers } = msg::load().expect("Unable to decode init config");
     unsafe {
         WORDLE = Some(Wordle {
+            owner: msg::source(),
+            allowed_callers: allowed_callers.into_iter().collect(),
             games: HashMap::new(),
+            shared_words: HashMap::new(),
+            random: Random::default(),
+            seen_words: HashMap::new(),
+            dictionary: BANK_OF_WORDS
+                .iter()
+                .map(|word| encode_word(word).expect("Default words are valid"))
+                .collect(),
+            dictionary_alphabet: Alphabet::Latin,
+            dictionary_version: 0,
+            pending_words: Vec::new(),
+            pending_alphabet: Alphabet::Latin,
         });
     }
 }
@@ -26,48 +224,84 @@ extern "C" fn handle() {
     let wordle = unsafe { WORDLE.as_mut().expect("The program is not initialized") };
 
     let reply = match action {
-        Action::StartGame { user } => {
-            let random_id = get_random_value(BANK_OF_WORDS.len() as u8);
-            let word = BANK_OF_WORDS[random_id as usize];
-            wordle.games.insert(user, word.to_string());
-            Event::GameStarted { user }
-        }
-        Action::CheckWord { user, word } => {
-            if word.len() != WORD_LENGTH {
-                panic!("The length of the word exceeds {}", WORD_LENGTH);
+        Action::AllowCaller { .. }
+        | Action::DisallowCaller { .. }
+        | Action::UploadWords { .. }
+        | Action::PublishDictionary
+            if msg::source() != wordle.owner =>
+        {
+            Err(Error::NotOwner)
+        }
+        Action::AllowCaller { caller } => {
+            wordle.allowed_callers.insert(caller);
+            Ok(Event::CallerAllowed { caller })
+        }
+        Action::DisallowCaller { caller } => {
+            wordle.allowed_callers.remove(&caller);
+            Ok(Event::CallerDisallowed { caller })
+        }
+        Action::UploadWords { alphabet, words } => {
+            if !wordle.pending_words.is_empty() && alphabet != wordle.pending_alphabet {
+                Err(Error::AlphabetMismatch)
+            } else if words.iter().any(|code| alphabet.decode(*code).is_none()) {
+                Err(Error::InvalidWord)
+            } else {
+                wordle.pending_alphabet = alphabet;
+                wordle.pending_words.extend(words);
+                Ok(Event::WordsUploaded {
+                    pending: wordle.pending_words.len() as u32,
+                })
             }
-            let key_word = wordle
-                .games
-                .get(&user)
-                .expect("There is no game with this user");
-            let mut matched_indices = Vec::with_capacity(WORD_LENGTH);
-            let mut key_indices = Vec::with_capacity(WORD_LENGTH);
-            for (i, (a, b)) in key_word.chars().zip(word.chars()).enumerate() {
-                if a == b {
-                    matched_indices.push(i as u8);
-                } else if key_word.contains(b) {
-                    key_indices.push(i as u8);
-                }
+        }
+        Action::PublishDictionary => {
+            if wordle.pending_words.is_empty() {
+                Err(Error::EmptyDictionary)
+            } else {
+                wordle.dictionary = core::mem::take(&mut wordle.pending_words);
+                wordle.dictionary_alphabet = wordle.pending_alphabet;
+                wordle.dictionary_version += 1;
+                // Indices seen so far refer to the previous dictionary
+                wordle.seen_words.clear();
+                Ok(Event::DictionaryPublished {
+                    version: wordle.dictionary_version,
+                    words: wordle.dictionary.len() as u32,
+                })
             }
-
-            Event::WordChecked {
+        }
+        _ if !wordle.allowed_callers.contains(&msg::source()) => Err(Error::UnauthorizedCaller),
+        Action::StartGame {
+            user,
+            game_id,
+            word_key,
+            puzzle,
+        } => wordle.start_game(user, game_id, word_key, puzzle),
+        Action::CheckWord {
+            user,
+            game_id,
+            word,
+        } => wordle.check_word(user, game_id, word),
+        Action::EndGame {
+            user,
+            game_id,
+            outcome,
+        } => match wordle.games.remove(&(msg::source(), user, game_id)) {
+            Some(_) => Ok(Event::GameEnded {
                 user,
-                correct_positions: matched_indices,
-                contained_in_word: key_indices,
-            }
+                game_id,
+                outcome,
+            }),
+            None => Err(Error::GameNotFound),
+        },
+        Action::RevealLetter {
+            user,
+            game_id,
+            known_positions,
+        } => wordle.reveal_letter(user, game_id, known_positions),
+        Action::ForgetWord { word_key } => {
+            wordle.shared_words.remove(&(msg::source(), word_key));
+            Ok(Event::WordForgotten { word_key })
         }
     };
 
     msg::reply(reply, 0).expect("Error in sending a reply");
 }
-
-static mut SEED: u8 = 0;
-
-pub fn get_random_value(range: u8) -> u8 {
-    let seed = unsafe { SEED };
-    unsafe { SEED = SEED.wrapping_add(1) };
-    let mut random_input: [u8; 32] = exec::program_id().into();
-    random_input[0] = random_input[0].wrapping_add(seed);
-    let (random, _) = exec::random(random_input).expect("Error in getting random number");
-    random[0] % range
-}
@@ -0,0 +1,48 @@
+use gstd::{exec, ActorId};
+
+/// Source of random values used to pick words from the bank.
+///
+/// Every draw mixes the user, the current block height and a nonce into the subject
+/// passed to `exec::random`, so that draws in the same block don't repeat.
+#[derive(Default)]
+pub struct Random {
+    nonce: u64,
+}
+
+impl Random {
+    /// Returns a uniformly distributed value in `0..range`.
+    pub fn next_u64(&mut self, user: ActorId, range: u64) -> u64 {
+        assert!(range > 0, "Range must not be empty");
+
+        // Values below `2^64 % range` would make the lowest results more likely than others
+        let threshold = range.wrapping_neg() % range;
+        loop {
+            for chunk in self.next_bytes(user).chunks_exact(8) {
+                let value = u64::from_le_bytes(chunk.try_into().expect("Chunk is 8 bytes long"));
+                if value >= threshold {
+                    return value % range;
+                }
+            }
+        }
+    }
+
+    /// Returns a uniformly distributed value in `0..range`.
+    pub fn next_u32(&mut self, user: ActorId, range: u32) -> u32 {
+        self.next_u64(user, u64::from(range)) as u32
+    }
+
+    fn next_bytes(&mut self, user: ActorId) -> [u8; 32] {
+        let mut subject: [u8; 32] = user.into();
+        let mixin = exec::block_height()
+            .to_le_bytes()
+            .into_iter()
+            .chain(self.nonce.to_le_bytes());
+        for (byte, extra) in subject.iter_mut().zip(mixin) {
+            *byte ^= extra;
+        }
+        self.nonce = self.nonce.wrapping_add(1);
+
+        let (random, _) = exec::random(subject).expect("Error in getting random number");
+        random
+    }
+}
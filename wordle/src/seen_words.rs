@@ -0,0 +1,50 @@
+use gstd::prelude::*;
+
+/// Compact set of the bank indices already assigned to a user, one bit per word.
+#[derive(Default)]
+pub struct SeenWords {
+    bits: Vec<u64>,
+    len: u32,
+}
+
+impl SeenWords {
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+        self.len = 0;
+    }
+
+    pub fn insert(&mut self, index: u32) {
+        let (block, bit) = (index as usize / 64, index % 64);
+        if self.bits.len() <= block {
+            self.bits.resize(block + 1, 0);
+        }
+
+        if self.bits[block] & (1 << bit) == 0 {
+            self.bits[block] |= 1 << bit;
+            self.len += 1;
+        }
+    }
+
+    /// Returns the `n`-th smallest index that is not in the set.
+    pub fn nth_unseen(&self, mut n: u32) -> u32 {
+        let blocks = self.bits.iter().copied().chain(core::iter::repeat(0));
+        for (index, block) in blocks.enumerate() {
+            let unseen = block.count_zeros();
+            if n < unseen {
+                // Drop the `n` lowest unseen bits to land on the one we are looking for
+                let mut remaining = !block;
+                for _ in 0..n {
+                    remaining &= remaining - 1;
+                }
+                return index as u32 * 64 + remaining.trailing_zeros();
+            }
+            n -= unseen;
+        }
+
+        unreachable!("There are always unseen indices past the last block")
+    }
+}
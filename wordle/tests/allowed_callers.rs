@@ -0,0 +1,135 @@
+mod utils;
+
+use gtest::{Log, System};
+use utils::*;
+use wordle_io::{Action, Alphabet, Error, Event, GameOutcome};
+
+#[test]
+fn start_game_should_work_when_caller_allowed() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: An allowed caller starts a game
+    let result = program.send(CALLER, start_game_action(0));
+
+    // Then: GameStarted event is replied
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::GameStarted {
+            user: USER.into(),
+            game_id: 0,
+            alphabet: Alphabet::Latin,
+            puzzle: puzzle_of(&result),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn start_game_should_be_rejected_when_caller_not_allowed() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: A user starts a game directly
+    let result = program.send(USER, start_game_action(0));
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(USER)
+        .payload(Err::<Event, _>(Error::UnauthorizedCaller));
+    assert!(!result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn allowed_callers_should_be_managed_by_owner_only() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: A non-owner allows itself
+    let result = program.send(
+        USER,
+        Action::AllowCaller {
+            caller: USER.into(),
+        },
+    );
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(USER)
+        .payload(Err::<Event, _>(Error::NotOwner));
+    assert!(result.contains(&log));
+
+    // When: The owner disallows the caller
+    let result = program.send(
+        OWNER,
+        Action::DisallowCaller {
+            caller: CALLER.into(),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Ok::<_, Error>(Event::CallerDisallowed {
+            caller: CALLER.into(),
+        }));
+    assert!(result.contains(&log));
+
+    // Then: The caller can no longer start games
+    let result = program.send(CALLER, start_game_action(0));
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::UnauthorizedCaller));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn games_should_be_kept_apart_by_caller() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: Another caller is allowed
+    const ANOTHER_CALLER: u64 = 5;
+    program.send(
+        OWNER,
+        Action::AllowCaller {
+            caller: ANOTHER_CALLER.into(),
+        },
+    );
+
+    // When: Both callers start a game with the same ID for the same user
+    program.send(CALLER, start_game_action(0));
+    program.send(ANOTHER_CALLER, start_game_action(0));
+
+    // Then:
+    // - Once its own game is ended, a caller can't end the game of the other one
+    // - The game of the other caller is still playable
+    let end_game_action = Action::EndGame {
+        user: USER.into(),
+        game_id: 0,
+        outcome: GameOutcome::Lose,
+    };
+    program.send(ANOTHER_CALLER, end_game_action.clone());
+    let result = program.send(ANOTHER_CALLER, end_game_action);
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(ANOTHER_CALLER)
+        .payload(Err::<Event, _>(Error::GameNotFound));
+    assert!(result.contains(&log));
+
+    let result = program.send(
+        CALLER,
+        Action::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: BANK_OF_WORDS[0].into(),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::GameNotFound));
+    assert!(!result.main_failed() && !result.contains(&log));
+}
@@ -0,0 +1,80 @@
+mod utils;
+
+use gtest::{Log, System};
+use utils::*;
+use wordle_io::{Action, Error, Event, MAX_ATTEMPTS};
+
+// Not in `BANK_OF_WORDS`, so it's always a wrong answer
+const WRONG_ANSWER: &str = "mouse";
+
+fn check_word_action(word: &str) -> Action {
+    Action::CheckWord {
+        user: USER.into(),
+        game_id: 0,
+        word: word.into(),
+    }
+}
+
+#[test]
+fn check_word_should_fail_when_no_attempts_left() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: All attempts of a game have been used
+    program.send(CALLER, start_game_action(0));
+    for _ in 0..MAX_ATTEMPTS {
+        let result = program.send(CALLER, check_word_action(WRONG_ANSWER));
+        assert!(!result.main_failed());
+    }
+
+    // When: The caller checks another word
+    let result = program.send(CALLER, check_word_action(WRONG_ANSWER));
+
+    // Then: The check is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::NoAttemptsLeft));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn check_word_should_fail_when_already_solved() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: The word of a game has been guessed
+    program.send(CALLER, start_game_action(0));
+    let answer = answer_of(&program, 0);
+
+    // When: The caller checks a word again
+    let result = program.send(CALLER, check_word_action(answer));
+
+    // Then: The check is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::AlreadySolved));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn check_word_should_fail_when_word_invalid() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: A game is in progress
+    program.send(CALLER, start_game_action(0));
+
+    for word in ["hous", "книга"] {
+        // When: The caller checks a word that isn't five letters of the game alphabet
+        let result = program.send(CALLER, check_word_action(word));
+
+        // Then: The check is rejected
+        let log = Log::builder()
+            .source(WORDLE_PROGRAM)
+            .dest(CALLER)
+            .payload(Err::<Event, _>(Error::InvalidWord));
+        assert!(!result.main_failed() && result.contains(&log));
+    }
+}
@@ -0,0 +1,263 @@
+mod utils;
+
+use gtest::{Log, System};
+use utils::*;
+use wordle_io::{
+    decode_word, encode_word, Action, Alphabet, Error, Event, LetterFeedback, PuzzleId, WordCode,
+    WORD_LENGTH,
+};
+
+fn encode_all(words: &[&str]) -> Vec<WordCode> {
+    words
+        .iter()
+        .map(|word| encode_word(word).unwrap())
+        .collect()
+}
+
+#[test]
+fn word_codes_should_round_trip() {
+    for word in BANK_OF_WORDS {
+        let code = encode_word(word).unwrap();
+        assert!(code < 1 << 25);
+        assert_eq!(decode_word(code).as_deref(), Some(word));
+    }
+
+    assert_eq!(encode_word("Horse"), None);
+    assert_eq!(encode_word("hors"), None);
+    assert_eq!(decode_word(1 << 25), None);
+    assert_eq!(decode_word(31), None);
+}
+
+#[test]
+fn word_codes_should_round_trip_in_every_alphabet() {
+    for (alphabet, word) in [
+        (Alphabet::Latin, "crane"),
+        (Alphabet::LatinWithDiacritics, "straß"),
+        (Alphabet::LatinWithDiacritics, "façon"),
+        (Alphabet::Cyrillic, "ёжики"),
+    ] {
+        let code = alphabet.encode(word).unwrap();
+        assert_eq!(alphabet.decode(code).as_deref(), Some(word));
+    }
+
+    // Length is measured in letters, not bytes
+    assert!(Alphabet::Cyrillic.is_word("книга"));
+    assert!(!Alphabet::Cyrillic.is_word("кни"));
+    assert_eq!(Alphabet::Latin.encode("façon"), None);
+    assert_eq!(Alphabet::Cyrillic.encode("crane"), None);
+    assert_eq!(Alphabet::Cyrillic.to_lowercase('Ё'), Some('ё'));
+    assert_eq!(Alphabet::LatinWithDiacritics.to_lowercase('É'), Some('é'));
+    assert_eq!(Alphabet::Latin.to_lowercase('1'), None);
+}
+
+#[test]
+fn upload_words_should_fail_when_not_owner() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: A non-owner uploads words
+    let result = program.send(
+        CALLER,
+        Action::UploadWords {
+            alphabet: Alphabet::Latin,
+            words: encode_all(&["crane"]),
+        },
+    );
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::NotOwner));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn upload_words_should_fail_when_code_is_invalid() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: The owner uploads a code that is not a word
+    let result = program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Latin,
+            words: vec![31],
+        },
+    );
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Err::<Event, _>(Error::InvalidWord));
+    assert!(result.contains(&log));
+
+    // And nothing is left to publish
+    let result = program.send(OWNER, Action::PublishDictionary);
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Err::<Event, _>(Error::EmptyDictionary));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn publish_dictionary_should_replace_words_of_new_games() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: The owner uploads a dictionary in chunks
+    let result = program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Latin,
+            words: encode_all(&["crane", "crane"]),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Ok::<_, Error>(Event::WordsUploaded { pending: 2 }));
+    assert!(result.contains(&log));
+
+    let result = program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Latin,
+            words: encode_all(&["crane"]),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Ok::<_, Error>(Event::WordsUploaded { pending: 3 }));
+    assert!(result.contains(&log));
+
+    // When: The owner publishes it
+    let result = program.send(OWNER, Action::PublishDictionary);
+
+    // Then: New games are given words from the published dictionary
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Ok::<_, Error>(Event::DictionaryPublished {
+            version: 1,
+            words: 3,
+        }));
+    assert!(result.contains(&log));
+
+    program.send(CALLER, start_game_action(0));
+    let result = program.send(
+        CALLER,
+        Action::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: "crane".into(),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::WordChecked {
+            user: USER.into(),
+            game_id: 0,
+            correct_positions: vec![0, 1, 2, 3, 4],
+            contained_in_word: vec![],
+            feedback: vec![LetterFeedback::Correct; WORD_LENGTH],
+        }));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn upload_words_should_fail_when_alphabet_changes_between_chunks() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: The owner uploads a chunk of latin words
+    program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Latin,
+            words: encode_all(&["crane"]),
+        },
+    );
+
+    // When: The next chunk uses another alphabet
+    let result = program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Cyrillic,
+            words: vec![Alphabet::Cyrillic.encode("книга").unwrap()],
+        },
+    );
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(OWNER)
+        .payload(Err::<Event, _>(Error::AlphabetMismatch));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn game_should_use_alphabet_of_published_dictionary() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: The owner publishes a cyrillic dictionary
+    program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Cyrillic,
+            words: vec![Alphabet::Cyrillic.encode("книга").unwrap()],
+        },
+    );
+    program.send(OWNER, Action::PublishDictionary);
+
+    // When: A game is started
+    let result = program.send(CALLER, start_game_action(0));
+
+    // Then:
+    // - The game is started with the cyrillic alphabet
+    // - Cyrillic guesses are scored letter by letter
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::GameStarted {
+            user: USER.into(),
+            game_id: 0,
+            alphabet: Alphabet::Cyrillic,
+            puzzle: PuzzleId {
+                dictionary_version: 1,
+                index: 0,
+            },
+        }));
+    assert!(result.contains(&log));
+
+    let result = program.send(
+        CALLER,
+        Action::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: "кинга".into(),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::WordChecked {
+            user: USER.into(),
+            game_id: 0,
+            correct_positions: vec![0, 3, 4],
+            contained_in_word: vec![1, 2],
+            feedback: vec![
+                LetterFeedback::Correct,
+                LetterFeedback::Present,
+                LetterFeedback::Present,
+                LetterFeedback::Correct,
+                LetterFeedback::Correct,
+            ],
+        }));
+    assert!(result.contains(&log));
+}
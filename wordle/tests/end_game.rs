@@ -0,0 +1,68 @@
+mod utils;
+
+use gtest::{Log, System};
+use utils::*;
+use wordle_io::{Action, Error, Event, GameOutcome};
+
+fn end_game_action(game_id: u64) -> Action {
+    Action::EndGame {
+        user: USER.into(),
+        game_id,
+        outcome: GameOutcome::Lose,
+    }
+}
+
+#[test]
+fn end_game_should_reject_further_checks() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: A game has been started
+    program.send(CALLER, start_game_action(0));
+
+    // When: The caller ends the game
+    let result = program.send(CALLER, end_game_action(0));
+
+    // Then:
+    // - GameEnded event is replied
+    // - Further checks are rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::GameEnded {
+            user: USER.into(),
+            game_id: 0,
+            outcome: GameOutcome::Lose,
+        }));
+    assert!(result.contains(&log));
+
+    let result = program.send(
+        CALLER,
+        Action::CheckWord {
+            user: USER.into(),
+            game_id: 0,
+            word: BANK_OF_WORDS[0].into(),
+        },
+    );
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::GameNotFound));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn end_game_should_fail_when_game_not_found() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: The caller ends a game that was never started
+    let result = program.send(CALLER, end_game_action(0));
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::GameNotFound));
+    assert!(result.contains(&log));
+}
@@ -0,0 +1,76 @@
+mod utils;
+
+use gtest::{Log, System};
+use utils::*;
+use wordle_io::{Action, Error, Event};
+
+fn reveal_letter_action(known_positions: Vec<u8>) -> Action {
+    Action::RevealLetter {
+        user: USER.into(),
+        game_id: 0,
+        known_positions,
+    }
+}
+
+#[test]
+fn reveal_letter_should_skip_known_positions() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: A game has been started, all words of the bank start with 'h'
+    program.send(CALLER, start_game_action(0));
+
+    // When: The caller asks for a letter other than the ones it knows
+    let result = program.send(CALLER, reveal_letter_action(vec![1, 2, 3, 4]));
+
+    // Then: The first unknown letter is revealed
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::LetterRevealed {
+            user: USER.into(),
+            game_id: 0,
+            position: 0,
+            letter: "h".into(),
+        }));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn reveal_letter_should_fail_when_every_position_is_known() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: A game has been started
+    program.send(CALLER, start_game_action(0));
+
+    // When: The caller already knows every position
+    let result = program.send(CALLER, reveal_letter_action(vec![0, 1, 2, 3, 4]));
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::NothingToReveal));
+    assert!(result.contains(&log));
+}
+
+#[test]
+fn reveal_letter_should_fail_when_solved() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: The game has been solved
+    program.send(CALLER, start_game_action(0));
+    answer_of(&program, 0);
+
+    // When: The caller asks for a letter
+    let result = program.send(CALLER, reveal_letter_action(vec![]));
+
+    // Then: The request is rejected
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Err::<Event, _>(Error::AlreadySolved));
+    assert!(result.contains(&log));
+}
@@ -0,0 +1,80 @@
+use gstd::prelude::Decode;
+use gtest::{Log, Program, RunResult, System};
+use wordle_io::{Action, Error, Event, GameId, InitConfig, LetterFeedback, PuzzleId, WORD_LENGTH};
+
+pub const WORDLE_PROGRAM: u64 = 1;
+pub const OWNER: u64 = 2;
+pub const CALLER: u64 = 3;
+pub const USER: u64 = 4;
+
+#[allow(unused)]
+pub const BANK_OF_WORDS: [&str; 3] = ["house", "human", "horse"];
+
+pub fn init_program(sys: &System) -> Program<'_> {
+    sys.init_logger();
+    let program = Program::current(sys);
+    let result = program.send(
+        OWNER,
+        InitConfig {
+            allowed_callers: vec![CALLER.into()],
+        },
+    );
+    assert!(!result.main_failed());
+    program
+}
+
+#[allow(unused)]
+pub fn start_game_action(game_id: GameId) -> Action {
+    Action::StartGame {
+        user: USER.into(),
+        game_id,
+        word_key: None,
+        puzzle: None,
+    }
+}
+
+/// Returns the puzzle of the game started by the message `result` is about.
+#[allow(unused)]
+pub fn puzzle_of(result: &RunResult) -> PuzzleId {
+    result
+        .log()
+        .iter()
+        .find_map(
+            |log| match Result::<Event, Error>::decode(&mut log.payload()) {
+                Ok(Ok(Event::GameStarted { puzzle, .. })) => Some(puzzle),
+                _ => None,
+            },
+        )
+        .expect("The game has started")
+}
+
+/// Finds out the word of the given game by checking every word of the bank,
+/// which solves the game.
+#[allow(unused)]
+pub fn answer_of(program: &Program, game_id: GameId) -> &'static str {
+    let guessed_log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::WordChecked {
+            user: USER.into(),
+            game_id,
+            correct_positions: vec![0, 1, 2, 3, 4],
+            contained_in_word: vec![],
+            feedback: vec![LetterFeedback::Correct; WORD_LENGTH],
+        }));
+
+    BANK_OF_WORDS
+        .into_iter()
+        .find(|word| {
+            let result = program.send(
+                CALLER,
+                Action::CheckWord {
+                    user: USER.into(),
+                    game_id,
+                    word: word.to_string(),
+                },
+            );
+            result.contains(&guessed_log)
+        })
+        .expect("The answer is one of the words in the bank")
+}
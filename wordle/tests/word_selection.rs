@@ -0,0 +1,195 @@
+mod utils;
+
+use gstd::collections::HashSet;
+use gtest::{Log, System};
+use utils::*;
+use wordle_io::{Action, Alphabet, Error, Event, PuzzleId};
+
+#[test]
+fn start_game_should_not_repeat_words_until_bank_is_exhausted() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: User plays as many games as there are words in the bank
+    let answers: HashSet<&str> = (0..BANK_OF_WORDS.len() as u64)
+        .map(|game_id| {
+            program.send(CALLER, start_game_action(game_id));
+            answer_of(&program, game_id)
+        })
+        .collect();
+
+    // Then: Every word of the bank has been given once
+    assert_eq!(answers.len(), BANK_OF_WORDS.len());
+
+    // When: User starts another game once the bank is exhausted
+    let game_id = BANK_OF_WORDS.len() as u64;
+    let result = program.send(CALLER, start_game_action(game_id));
+
+    // Then: The game is started with one of the words of the bank
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::GameStarted {
+            user: USER.into(),
+            game_id,
+            alphabet: Alphabet::Latin,
+            puzzle: puzzle_of(&result),
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+    assert!(BANK_OF_WORDS.contains(&answer_of(&program, game_id)));
+}
+
+#[test]
+fn start_game_should_share_word_with_same_key() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // When: Several games are started with the same word key
+    for game_id in 0..2 {
+        program.send(
+            CALLER,
+            Action::StartGame {
+                user: USER.into(),
+                game_id,
+                word_key: Some(7),
+                puzzle: None,
+            },
+        );
+    }
+
+    // Then: The games are given the same word
+    assert_eq!(answer_of(&program, 0), answer_of(&program, 1));
+}
+
+#[test]
+fn forget_word_should_stop_sharing_word_of_key() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: A game has been started with a word key
+    let start_game_with_key = |game_id| Action::StartGame {
+        user: USER.into(),
+        game_id,
+        word_key: Some(7),
+        puzzle: None,
+    };
+    program.send(CALLER, start_game_with_key(0));
+    let answer = answer_of(&program, 0);
+
+    // When: The caller forgets the word of the key
+    let result = program.send(CALLER, Action::ForgetWord { word_key: 7 });
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::WordForgotten { word_key: 7 }));
+    assert!(result.contains(&log));
+
+    // Then: A new game with the key is given a word the user hasn't seen yet
+    program.send(CALLER, start_game_with_key(1));
+    assert_ne!(answer_of(&program, 1), answer);
+}
+
+#[test]
+fn start_game_should_replay_puzzle() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    // Given: A game has been played
+    let result = program.send(CALLER, start_game_action(0));
+    let puzzle = puzzle_of(&result);
+    assert_eq!(puzzle.dictionary_version, 0);
+    let answer = answer_of(&program, 0);
+
+    // When: Another game is started with the puzzle of the first one
+    let result = program.send(
+        CALLER,
+        Action::StartGame {
+            user: USER.into(),
+            game_id: 1,
+            word_key: None,
+            puzzle: Some(puzzle),
+        },
+    );
+
+    // Then: The game is given the same puzzle and word
+    assert_eq!(puzzle_of(&result), puzzle);
+    assert_eq!(answer_of(&program, 1), answer);
+}
+
+#[test]
+fn start_game_should_fail_when_puzzle_not_found() {
+    let system = System::new();
+    let program = init_program(&system);
+
+    for puzzle in [
+        // Beyond the end of the dictionary
+        PuzzleId {
+            dictionary_version: 0,
+            index: BANK_OF_WORDS.len() as u32,
+        },
+        // From a dictionary that was never published
+        PuzzleId {
+            dictionary_version: 1,
+            index: 0,
+        },
+    ] {
+        // When: A game is started with a puzzle of no dictionary in use
+        let result = program.send(
+            CALLER,
+            Action::StartGame {
+                user: USER.into(),
+                game_id: 0,
+                word_key: None,
+                puzzle: Some(puzzle),
+            },
+        );
+
+        // Then: The request is rejected
+        let log = Log::builder()
+            .source(WORDLE_PROGRAM)
+            .dest(CALLER)
+            .payload(Err::<Event, _>(Error::PuzzleNotFound));
+        assert!(result.contains(&log));
+    }
+}
+
+#[test]
+fn start_game_should_share_word_with_its_alphabet_after_dictionary_replaced() {
+    let system = System::new();
+    let program = init_program(&system);
+    let start_game_with_key = |game_id| Action::StartGame {
+        user: USER.into(),
+        game_id,
+        word_key: Some(7),
+        puzzle: None,
+    };
+
+    // Given:
+    // - A game has been started with a word key
+    // - The dictionary is replaced by one of another alphabet
+    let puzzle = puzzle_of(&program.send(CALLER, start_game_with_key(0)));
+    program.send(
+        OWNER,
+        Action::UploadWords {
+            alphabet: Alphabet::Cyrillic,
+            words: vec![Alphabet::Cyrillic.encode("книга").unwrap()],
+        },
+    );
+    program.send(OWNER, Action::PublishDictionary);
+
+    // When: Another game is started with the same key
+    let result = program.send(CALLER, start_game_with_key(1));
+
+    // Then: The game is given the shared word in its own alphabet
+    let log = Log::builder()
+        .source(WORDLE_PROGRAM)
+        .dest(CALLER)
+        .payload(Ok::<_, Error>(Event::GameStarted {
+            user: USER.into(),
+            game_id: 1,
+            alphabet: Alphabet::Latin,
+            puzzle,
+        }));
+    assert!(!result.main_failed() && result.contains(&log));
+    assert_eq!(answer_of(&program, 1), answer_of(&program, 0));
+}